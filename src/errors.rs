@@ -71,6 +71,8 @@ pub enum GdalError {
     },
     #[error("Unsupported GDAL geometry type")]
     UnsupportedGdalGeometryType(OGRwkbGeometryType::Type),
+    #[error("Converting GDAL geometry type '{0}' would drop its Z or M values")]
+    LossyGeometryConversion(OGRwkbGeometryType::Type),
     #[error("Unable to unlink mem file: {file_name}")]
     UnlinkMemFile { file_name: String },
     #[error("BadArgument")]
@@ -86,6 +88,12 @@ pub enum GdalError {
     IntConversionError(#[from] TryFromIntError),
     #[error("Buffer length {0} does not match raster size {1:?}")]
     BufferSizeMismatch(usize, (usize, usize)),
+    #[cfg(feature = "proj")]
+    #[error("PROJ method '{method_name}' failed. Error msg: '{msg}'")]
+    ProjError {
+        method_name: &'static str,
+        msg: String,
+    },
     #[error("Dataset is not thread-safe")]
     DatasetNotThreadSafe,
 }
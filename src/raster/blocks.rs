@@ -0,0 +1,229 @@
+#[cfg(all(feature = "rayon", any(major_ge_4, all(major_is_3, minor_ge_10))))]
+use rayon::iter::IndexedParallelIterator;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::errors::*;
+use crate::raster::{Buffer, GdalType, RasterBand};
+#[cfg(all(feature = "rayon", any(major_ge_4, all(major_is_3, minor_ge_10))))]
+use crate::ThreadSafeDataset;
+
+/// A rectangular region of a raster, in pixel coordinates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Window {
+    /// The position of the top left corner, as `(x, y)`.
+    pub offset: (isize, isize),
+    /// The size of the region, as `(cols, rows)`.
+    pub size: (usize, usize),
+}
+
+impl Window {
+    pub fn new(offset: (isize, isize), size: (usize, usize)) -> Self {
+        Self { offset, size }
+    }
+}
+
+/// A block of a band, as the `(x, y)` block index, the [`Window`] it covers and its data.
+pub type Block<T> = ((usize, usize), Window, Buffer<T>);
+
+/// The index and the window of each block of a band, in row-major order.
+pub(super) fn block_windows(
+    size: (usize, usize),
+    block_size: (usize, usize),
+) -> impl Iterator<Item = ((usize, usize), Window)> {
+    let count = block_count(size, block_size);
+    (0..count.1).flat_map(move |y| {
+        (0..count.0).map(move |x| {
+            let offset = (x * block_size.0, y * block_size.1);
+            let window = Window::new(
+                (offset.0 as isize, offset.1 as isize),
+                (
+                    block_size.0.min(size.0 - offset.0),
+                    block_size.1.min(size.1 - offset.1),
+                ),
+            );
+            ((x, y), window)
+        })
+    })
+}
+
+fn block_count(size: (usize, usize), block_size: (usize, usize)) -> (usize, usize) {
+    (
+        size.0.div_ceil(block_size.0.max(1)),
+        size.1.div_ceil(block_size.1.max(1)),
+    )
+}
+
+/// Iterator over the blocks of a [`RasterBand`], returned by [`RasterBand::blocks`].
+pub struct BlockIter<'b, 'a, T> {
+    band: &'b RasterBand<'a>,
+    windows: Box<dyn Iterator<Item = ((usize, usize), Window)>>,
+    len: usize,
+    _type: std::marker::PhantomData<T>,
+}
+
+impl<T: Copy + GdalType> Iterator for BlockIter<'_, '_, T> {
+    type Item = Result<Block<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, window) = self.windows.next()?;
+        self.len -= 1;
+        let buffer = self
+            .band
+            .read_as::<T>(window.offset, window.size, window.size, None);
+        Some(buffer.map(|buffer| (index, window, buffer)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: Copy + GdalType> ExactSizeIterator for BlockIter<'_, '_, T> {}
+
+impl<'a> RasterBand<'a> {
+    /// The number of blocks of this band, as `(cols, rows)`, including the partial blocks at the
+    /// right and bottom edges.
+    ///
+    /// See also: [`RasterBand::block_size`]
+    pub fn block_count(&self) -> (usize, usize) {
+        block_count(self.size(), self.block_size())
+    }
+
+    /// Iterate over the blocks of this band, in row-major order.
+    ///
+    /// Each item is a [`Block`]: the `(x, y)` block index, the [`Window`] covered by the block
+    /// and its data, converted to `T` if needed. The blocks at the right and bottom edges are
+    /// trimmed to the size of the band, so the buffer shape always matches `window.size`.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::Dataset;
+    ///
+    /// let dataset = Dataset::open("fixtures/m_3607824_se_17_1_20160620_sub.tif")?;
+    /// let band = dataset.rasterband(1)?;
+    /// let mut sum = 0u64;
+    /// for block in band.blocks::<u8>() {
+    ///     let (_index, _window, buffer) = block?;
+    ///     sum += buffer.data().iter().map(|&v| v as u64).sum::<u64>();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocks<T: Copy + GdalType>(&self) -> BlockIter<'_, 'a, T> {
+        let (size, block_size) = (self.size(), self.block_size());
+        let count = block_count(size, block_size);
+        BlockIter {
+            band: self,
+            windows: Box::new(block_windows(size, block_size)),
+            len: count.0 * count.1,
+            _type: std::marker::PhantomData,
+        }
+    }
+
+    /// Write blocks produced in parallel, e.g. by mapping over the blocks returned by
+    /// [`RasterBand::blocks`] or, with GDAL 3.10 or later, `ThreadSafeDataset::par_blocks`, into
+    /// this band.
+    ///
+    /// GDAL doesn't support concurrent writes, so `blocks` is driven on the rayon thread pool
+    /// while the blocks are written one at a time, as they arrive, from the calling thread.
+    /// Each block is written to its [`Window`] with [`RasterBand::write`].
+    ///
+    /// Stops at the first error, either returned by `blocks` or by a write.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::{Dataset, DriverManager};
+    /// use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    ///
+    /// let input = Dataset::open("fixtures/tinymarble.tif")?;
+    /// let blocks = input
+    ///     .rasterband(1)?
+    ///     .blocks::<u8>()
+    ///     .collect::<gdal::errors::Result<Vec<_>>>()?;
+    /// let (cols, rows) = input.raster_size();
+    /// let driver = DriverManager::get_driver_by_name("MEM")?;
+    /// let output = driver.create_with_band_type::<u8, _>("", cols, rows, 1)?;
+    /// let blocks = blocks.into_par_iter().map(|(_index, window, mut buffer)| {
+    ///     buffer.data_mut().iter_mut().for_each(|v| *v = 255 - *v);
+    ///     Ok((window, buffer))
+    /// });
+    /// output.rasterband(1)?.par_write_blocks(blocks)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn par_write_blocks<T, I>(&mut self, blocks: I) -> Result<()>
+    where
+        T: Copy + GdalType + Send,
+        I: IntoParallelIterator<Item = Result<(Window, Buffer<T>)>> + Send,
+    {
+        let (tx, rx) = std::sync::mpsc::sync_channel(rayon::current_num_threads());
+        std::thread::scope(|s| {
+            s.spawn(move || {
+                // A send fails once the receiver is gone, after an error: stop producing.
+                let _ = blocks
+                    .into_par_iter()
+                    .try_for_each_with(tx, |tx, block| tx.send(block));
+            });
+            // `rx` is dropped on early return, which lets the producers stop.
+            for block in rx {
+                let (window, mut buffer) = block?;
+                self.write(window.offset, window.size, &mut buffer)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(all(feature = "rayon", any(major_ge_4, all(major_is_3, minor_ge_10))))]
+impl ThreadSafeDataset {
+    /// Read the blocks of a band in parallel, on the rayon thread pool.
+    ///
+    /// This is the parallel counterpart of [`RasterBand::blocks`], yielding the same items in
+    /// no particular order. The results can be written back with
+    /// [`RasterBand::par_write_blocks`].
+    ///
+    /// # Arguments
+    /// * `band_index` - the _1-based_ index of the band to read
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn par_blocks<T: Copy + GdalType + Send>(
+        &self,
+        band_index: usize,
+    ) -> Result<impl IndexedParallelIterator<Item = Result<Block<T>>> + '_> {
+        let band = self.rasterband(band_index)?;
+        let windows = block_windows(band.size(), band.block_size()).collect::<Vec<_>>();
+        Ok(windows.into_par_iter().map(move |(index, window)| {
+            let band = self.rasterband(band_index)?;
+            let buffer = band.read_as::<T>(window.offset, window.size, window.size, None)?;
+            Ok((index, window, buffer))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_windows() {
+        let windows = block_windows((40, 20), (16, 16)).collect::<Vec<_>>();
+        assert_eq!(block_count((40, 20), (16, 16)), (3, 2));
+        assert_eq!(
+            windows,
+            vec![
+                ((0, 0), Window::new((0, 0), (16, 16))),
+                ((1, 0), Window::new((16, 0), (16, 16))),
+                ((2, 0), Window::new((32, 0), (8, 16))),
+                ((0, 1), Window::new((0, 16), (16, 4))),
+                ((1, 1), Window::new((16, 16), (16, 4))),
+                ((2, 1), Window::new((32, 16), (8, 4))),
+            ]
+        );
+    }
+}
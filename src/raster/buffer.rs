@@ -4,7 +4,7 @@ use std::slice::{Iter, IterMut};
 use std::vec::IntoIter;
 
 #[cfg(feature = "ndarray")]
-use ndarray::Array2;
+use ndarray::{Array2, Array3};
 
 /// [`Buffer<T>`] manages cell values in in raster I/O operations.
 ///
@@ -232,10 +232,264 @@ impl<T: GdalType + Copy> From<Array2<T>> for Buffer<T> {
     }
 }
 
+/// Memory layout of the bands in a [`Buffer3<T>`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Interleaving {
+    /// Band-sequential: each band is stored in full before the next one (`RRR…GGG…BBB…`).
+    #[default]
+    Band,
+    /// Pixel-interleaved: the values of all bands are stored together for each pixel
+    /// (`RGBRGBRGB…`).
+    Pixel,
+    /// Line-interleaved: each row is stored once per band before the next row
+    /// (`RRR GGG BBB RRR …`).
+    Line,
+}
+
+impl Interleaving {
+    /// Returns the distance between consecutive pixels, rows and bands, in elements, for a
+    /// buffer of shape `(cols, rows, bands)`.
+    pub(crate) fn strides(self, shape: (usize, usize, usize)) -> (usize, usize, usize) {
+        let (cols, rows, bands) = shape;
+        match self {
+            Interleaving::Band => (1, cols, cols * rows),
+            Interleaving::Pixel => (bands, bands * cols, 1),
+            Interleaving::Line => (1, cols * bands, cols),
+        }
+    }
+}
+
+/// [`Buffer3<T>`] manages cell values of several bands in dataset-level raster I/O
+/// operations, such as [`Dataset::read_as`][crate::Dataset::read_as].
+///
+/// It conceptually represents a 3-D array backed by a `Vec<T>` to represent `shape`
+/// (cols, rows, bands), where the bands are laid out in memory according to an
+/// [`Interleaving`].
+///
+/// 3-D indexing is available through `Index<(usize, usize, usize)>` and
+/// `IndexMut<(usize, usize, usize)>` implementations, using `(band, row, col)` whatever the
+/// interleaving.
+///
+/// If the `ndarray` feature is enabled, a [`Buffer3<T>`] can be converted (without copy)
+/// to an `Array3<T>` via [`Buffer3<T>::to_array()`]. The axes of the array follow the memory
+/// layout: `(bands, rows, cols)` for [`Interleaving::Band`], `(rows, cols, bands)` for
+/// [`Interleaving::Pixel`] and `(rows, bands, cols)` for [`Interleaving::Line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Buffer3<T> {
+    shape: (usize, usize, usize),
+    interleaving: Interleaving,
+    data: Vec<T>,
+}
+
+impl<T: GdalType> Buffer3<T> {
+    /// Construct a new buffer from `shape` (`(cols, rows, bands)`), the layout of the bands
+    /// and `Vec<T>`.
+    ///
+    /// # Panics
+    /// Will panic if `shape.0 * shape.1 * shape.2 != data.len()`.
+    pub fn new(shape: (usize, usize, usize), interleaving: Interleaving, data: Vec<T>) -> Self {
+        assert_eq!(
+            shape.0 * shape.1 * shape.2,
+            data.len(),
+            "shape {}*{}*{}={} does not match length {}",
+            shape.0,
+            shape.1,
+            shape.2,
+            shape.0 * shape.1 * shape.2,
+            data.len()
+        );
+        Buffer3 {
+            shape,
+            interleaving,
+            data,
+        }
+    }
+
+    /// Destructures `self` into constituent parts.
+    pub fn into_shape_and_vec(self) -> ((usize, usize, usize), Vec<T>) {
+        (self.shape, self.data)
+    }
+
+    /// Returns the width (number of columns) of the buffer.
+    #[doc(alias = "columns")]
+    pub fn width(&self) -> usize {
+        self.shape.0
+    }
+
+    /// Returns the height (number of rows) of the buffer.
+    #[doc(alias = "rows")]
+    pub fn height(&self) -> usize {
+        self.shape.1
+    }
+
+    /// Returns the number of bands of the buffer.
+    pub fn band_count(&self) -> usize {
+        self.shape.2
+    }
+
+    /// Gets the 3-d shape of the buffer.
+    ///
+    /// Returns `(cols, rows, bands)`
+    pub fn shape(&self) -> (usize, usize, usize) {
+        self.shape
+    }
+
+    /// Returns the memory layout of the bands.
+    pub fn interleaving(&self) -> Interleaving {
+        self.interleaving
+    }
+
+    /// Get a slice over the buffer contents, in the order given by [`Buffer3::interleaving`].
+    pub fn data(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Get a mutable slice over the buffer contents, in the order given by
+    /// [`Buffer3::interleaving`].
+    pub fn data_mut(&mut self) -> &mut [T] {
+        self.data.as_mut_slice()
+    }
+
+    /// Get the number of elements in the buffer
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Determine if the buffer has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Copy the band at the 0-based index `band` into a [`Buffer<T>`].
+    ///
+    /// # Panics
+    /// Will panic if `band` is out of bounds.
+    pub fn band(&self, band: usize) -> Buffer<T>
+    where
+        T: Copy,
+    {
+        let (cols, rows, _) = self.shape;
+        let mut data = Vec::with_capacity(cols * rows);
+        for row in 0..rows {
+            for col in 0..cols {
+                data.push(self[(band, row, col)]);
+            }
+        }
+        Buffer::new((cols, rows), data)
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Convert `self` into an [`ndarray::Array3<T>`], whose axes follow the memory layout of
+    /// the buffer.
+    pub fn to_array(self) -> crate::errors::Result<Array3<T>> {
+        let (cols, rows, bands) = self.shape;
+        let shape = match self.interleaving {
+            Interleaving::Band => (bands, rows, cols),
+            Interleaving::Pixel => (rows, cols, bands),
+            Interleaving::Line => (rows, bands, cols),
+        };
+        Ok(Array3::from_shape_vec(shape, self.data)?)
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Create a buffer from an [`ndarray::Array3<T>`] whose axes follow the memory layout
+    /// given by `interleaving`, as returned by [`Buffer3::to_array`].
+    pub fn from_array(array: Array3<T>, interleaving: Interleaving) -> Self
+    where
+        T: Copy,
+    {
+        let &[a, b, c] = array.shape() else {
+            unreachable!("Array3 has three axes")
+        };
+        let shape = match interleaving {
+            Interleaving::Band => (c, b, a),
+            Interleaving::Pixel => (b, a, c),
+            Interleaving::Line => (c, a, b),
+        };
+        let data: Vec<T> = if array.is_standard_layout() {
+            let (data, offset) = array.into_raw_vec_and_offset();
+            match offset {
+                None | Some(0) => data,
+                Some(offset) => data[offset..].to_vec(),
+            }
+        } else {
+            array.iter().copied().collect()
+        };
+        Buffer3::new(shape, interleaving, data)
+    }
+
+    #[cold]
+    #[inline(never)]
+    #[track_caller]
+    fn panic_bad_index(shape: (usize, usize, usize), coord: (usize, usize, usize)) -> ! {
+        panic!("index out of bounds: buffer has shape `{shape:?}` but coordinate `{coord:?}` was requested");
+    }
+
+    #[inline]
+    #[track_caller]
+    fn vec_index_for(&self, coord: (usize, usize, usize)) -> usize {
+        if coord.0 >= self.shape.2 || coord.1 >= self.shape.1 || coord.2 >= self.shape.0 {
+            Self::panic_bad_index(self.shape, coord);
+        }
+        let (pixel, line, band) = self.interleaving.strides(self.shape);
+        coord.0 * band + coord.1 * line + coord.2 * pixel
+    }
+}
+
+impl<T: GdalType> Index<(usize, usize, usize)> for Buffer3<T> {
+    type Output = T;
+    fn index(&self, index: (usize, usize, usize)) -> &Self::Output {
+        &self.data[self.vec_index_for(index)]
+    }
+}
+
+impl<T: GdalType> IndexMut<(usize, usize, usize)> for Buffer3<T> {
+    fn index_mut(&mut self, index: (usize, usize, usize)) -> &mut Self::Output {
+        let idx = self.vec_index_for(index);
+        &mut self.data[idx]
+    }
+}
+
+impl<T: GdalType> IntoIterator for Buffer3<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T: GdalType> IntoIterator for &'a Buffer3<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, T: GdalType> IntoIterator for &'a mut Buffer3<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: GdalType> TryFrom<Buffer3<T>> for Array3<T> {
+    type Error = crate::errors::GdalError;
+
+    fn try_from(value: Buffer3<T>) -> Result<Self, Self::Error> {
+        value.to_array()
+    }
+}
+
 #[cfg(feature = "ndarray")]
 #[cfg(test)]
 mod tests {
-    use crate::raster::Buffer;
+    use crate::raster::{Buffer, Buffer3, Interleaving};
     use ndarray::{arr2, s, Array2, ShapeBuilder};
 
     #[test]
@@ -346,6 +600,50 @@ mod tests {
         assert_eq!(99, b[(0, 1)]);
     }
 
+    #[test]
+    fn buffer3_interleaving() {
+        // 3 cols, 2 rows, 2 bands; values are band * 100 + row * 10 + col
+        let expected = |(b, r, c): (usize, usize, usize)| (b * 100 + r * 10 + c) as i32;
+        let band = Buffer3::new(
+            (3, 2, 2),
+            Interleaving::Band,
+            vec![0, 1, 2, 10, 11, 12, 100, 101, 102, 110, 111, 112],
+        );
+        let pixel = Buffer3::new(
+            (3, 2, 2),
+            Interleaving::Pixel,
+            vec![0, 100, 1, 101, 2, 102, 10, 110, 11, 111, 12, 112],
+        );
+        let line = Buffer3::new(
+            (3, 2, 2),
+            Interleaving::Line,
+            vec![0, 1, 2, 100, 101, 102, 10, 11, 12, 110, 111, 112],
+        );
+        for buffer in [&band, &pixel, &line] {
+            for b in 0..2 {
+                for r in 0..2 {
+                    for c in 0..3 {
+                        assert_eq!(buffer[(b, r, c)], expected((b, r, c)));
+                    }
+                }
+            }
+            assert_eq!(
+                buffer.band(1),
+                Buffer::new((3, 2), vec![100, 101, 102, 110, 111, 112])
+            );
+        }
+
+        let a = pixel.clone().to_array().unwrap();
+        assert_eq!(a.shape(), [2, 3, 2]);
+        assert_eq!(a[[1, 2, 1]], 112);
+        assert_eq!(Buffer3::from_array(a, Interleaving::Pixel), pixel);
+
+        let a = line.clone().to_array().unwrap();
+        assert_eq!(a.shape(), [2, 2, 3]);
+        assert_eq!(a[[1, 0, 2]], 12);
+        assert_eq!(Buffer3::from_array(a, Interleaving::Line), line);
+    }
+
     #[test]
     #[should_panic]
     fn index_bounds_panic() {
@@ -0,0 +1,335 @@
+use std::ffi::c_void;
+use std::mem::MaybeUninit;
+
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::errors::*;
+use crate::raster::blocks::block_windows;
+use crate::raster::{Buffer, GdalType, RasterBand, Window};
+
+/// Options that specify how [`process_chunks`] splits the bands into chunks.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ChunkOptions {
+    /// The size of the chunks as `(cols, rows)`, not counting the halo. Defaults to the block
+    /// size of the first input band.
+    pub chunk_size: Option<(usize, usize)>,
+
+    /// The number of pixels added on each side of the chunks, so that neighbourhood operations
+    /// can look past the chunk edges. Defaults to `0`.
+    pub halo: usize,
+
+    /// The value of the halo pixels lying outside of the raster, for input bands without a
+    /// nodata value. Bands with a nodata value use it instead, if it can be represented in the
+    /// processing type. Defaults to `0`.
+    pub fill_value: f64,
+}
+
+/// A chunk of the input bands of [`process_chunks`], surrounded by its halo.
+#[derive(Debug)]
+pub struct Neighbourhood<T> {
+    window: Window,
+    halo: usize,
+    bands: Vec<Buffer<T>>,
+    no_data: Vec<Option<T>>,
+}
+
+impl<T: Copy + GdalType + PartialEq> Neighbourhood<T> {
+    /// The region of the raster covered by this chunk, not counting the halo. The closure of
+    /// [`process_chunks`] must return buffers of this size.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// The number of pixels around the chunk, on each side.
+    pub fn halo(&self) -> usize {
+        self.halo
+    }
+
+    /// The data of each input band, including the halo, with a shape of
+    /// `(cols + 2 * halo, rows + 2 * halo)`.
+    ///
+    /// The pixels of the halo lying outside of the raster are set to the nodata value of the
+    /// band, or to [`ChunkOptions::fill_value`].
+    pub fn bands(&self) -> &[Buffer<T>] {
+        &self.bands
+    }
+
+    /// The nodata value of an input band, converted to `T`, or `None` if the band has no nodata
+    /// value or if it can't be represented exactly in `T`.
+    ///
+    /// # Arguments
+    /// * `band` - the _0-based_ position of the band in the inputs
+    pub fn no_data_value(&self, band: usize) -> Option<T> {
+        self.no_data[band]
+    }
+
+    /// Returns whether `value` is the nodata value of an input band, including NaN nodata values.
+    ///
+    /// # Arguments
+    /// * `band` - the _0-based_ position of the band in the inputs
+    #[allow(clippy::eq_op)]
+    pub fn is_no_data(&self, band: usize, value: T) -> bool {
+        // NaN is the only value that isn't equal to itself
+        self.no_data[band]
+            .is_some_and(|no_data| no_data == value || (no_data != no_data && value != value))
+    }
+
+    /// The value of a pixel of an input band, or `None` for nodata.
+    ///
+    /// # Arguments
+    /// * `band` - the _0-based_ position of the band in the inputs
+    /// * `pixel` - the `(x, y)` position of the pixel, relative to the top left corner of the
+    ///   chunk; the halo lies at negative positions and past the chunk size
+    ///
+    /// # Panics
+    /// If `pixel` lies outside of the chunk and its halo.
+    pub fn value(&self, band: usize, pixel: (isize, isize)) -> Option<T> {
+        let halo = self.halo as isize;
+        let col = usize::try_from(pixel.0 + halo).expect("pixel must be within the halo");
+        let row = usize::try_from(pixel.1 + halo).expect("pixel must be within the halo");
+        let value = self.bands[band][(row, col)];
+        (!self.is_no_data(band, value)).then_some(value)
+    }
+}
+
+/// Converts a value with the same rounding and clamping rules as GDAL.
+fn convert<S: Copy + GdalType, D: Copy + GdalType>(value: S) -> D {
+    let mut result = MaybeUninit::<D>::uninit();
+    unsafe {
+        gdal_sys::GDALCopyWords(
+            &value as *const S as *const c_void,
+            S::gdal_ordinal(),
+            0,
+            result.as_mut_ptr() as *mut c_void,
+            D::gdal_ordinal(),
+            0,
+            1,
+        );
+        result.assume_init()
+    }
+}
+
+/// Converts a nodata value, or returns `None` if it can't be represented exactly in `T`, so
+/// that valid pixels aren't mistaken for a rounded or clamped nodata value.
+fn convert_no_data<T: Copy + GdalType>(value: f64) -> Option<T> {
+    let converted = convert::<f64, T>(value);
+    let round_trip = convert::<T, f64>(converted);
+    (round_trip == value || (round_trip.is_nan() && value.is_nan())).then_some(converted)
+}
+
+/// The state shared by the chunks of a [`process_chunks`] run.
+struct ChunkReader<T> {
+    size: (usize, usize),
+    chunk_size: (usize, usize),
+    halo: usize,
+    no_data: Vec<Option<T>>,
+    fill: Vec<T>,
+}
+
+impl<T: Copy + GdalType> ChunkReader<T> {
+    fn new(inputs: &[RasterBand], outputs: &[RasterBand], options: &ChunkOptions) -> Result<Self> {
+        let Some(first) = inputs.first() else {
+            return Err(GdalError::BadArgument(
+                "at least one input band is required".to_string(),
+            ));
+        };
+        let size = first.size();
+        if inputs.iter().chain(outputs).any(|band| band.size() != size) {
+            return Err(GdalError::BadArgument(
+                "input and output bands must have the same size".to_string(),
+            ));
+        }
+        let chunk_size = options.chunk_size.unwrap_or_else(|| first.block_size());
+        if chunk_size.0 == 0 || chunk_size.1 == 0 {
+            return Err(GdalError::BadArgument(
+                "chunk size must not be empty".to_string(),
+            ));
+        }
+
+        let no_data = inputs
+            .iter()
+            .map(|band| band.no_data_value().and_then(convert_no_data))
+            .collect::<Vec<_>>();
+        let fill = no_data
+            .iter()
+            .map(|no_data| no_data.unwrap_or_else(|| convert(options.fill_value)))
+            .collect();
+        Ok(Self {
+            size,
+            chunk_size,
+            halo: options.halo,
+            no_data,
+            fill,
+        })
+    }
+
+    fn windows(&self) -> impl Iterator<Item = Window> {
+        block_windows(self.size, self.chunk_size).map(|(_, window)| window)
+    }
+
+    fn read(&self, inputs: &[RasterBand], window: Window) -> Result<Neighbourhood<T>> {
+        let halo = self.halo as isize;
+        // the chunk with its halo, and the part of it that lies within the raster
+        let outer = (window.offset.0 - halo, window.offset.1 - halo);
+        let outer_size = (window.size.0 + 2 * self.halo, window.size.1 + 2 * self.halo);
+        let start = (outer.0.max(0), outer.1.max(0));
+        let end = (
+            (outer.0 + outer_size.0 as isize).min(self.size.0 as isize),
+            (outer.1 + outer_size.1 as isize).min(self.size.1 as isize),
+        );
+        let read_size = ((end.0 - start.0) as usize, (end.1 - start.1) as usize);
+
+        let mut bands = Vec::with_capacity(inputs.len());
+        for (band, &fill) in inputs.iter().zip(&self.fill) {
+            let buffer = band.read_as::<T>(start, read_size, read_size, None)?;
+            if read_size == outer_size {
+                bands.push(buffer);
+                continue;
+            }
+
+            let mut data = vec![fill; outer_size.0 * outer_size.1];
+            let col = (start.0 - outer.0) as usize;
+            let row = (start.1 - outer.1) as usize;
+            for (r, line) in buffer.data().chunks_exact(read_size.0).enumerate() {
+                let offset = (row + r) * outer_size.0 + col;
+                data[offset..offset + read_size.0].copy_from_slice(line);
+            }
+            bands.push(Buffer::new(outer_size, data));
+        }
+
+        Ok(Neighbourhood {
+            window,
+            halo: self.halo,
+            bands,
+            no_data: self.no_data.clone(),
+        })
+    }
+}
+
+fn write_chunk<U: Copy + GdalType>(
+    outputs: &mut [RasterBand],
+    window: Window,
+    buffers: Vec<Buffer<U>>,
+) -> Result<()> {
+    if buffers.len() != outputs.len() {
+        return Err(GdalError::BadArgument(format!(
+            "expected {} output buffers, got {}",
+            outputs.len(),
+            buffers.len()
+        )));
+    }
+    for (band, mut buffer) in outputs.iter_mut().zip(buffers) {
+        if buffer.shape() != window.size {
+            return Err(GdalError::BadArgument(format!(
+                "output buffer shape {:?} must match the chunk size {:?}",
+                buffer.shape(),
+                window.size
+            )));
+        }
+        band.write(window.offset, window.size, &mut buffer)?;
+    }
+    Ok(())
+}
+
+/// Process bands chunk by chunk, e.g. to apply focal filters to rasters that don't fit in
+/// memory.
+///
+/// The inputs are split into chunks of [`ChunkOptions::chunk_size`], each read with a halo of
+/// [`ChunkOptions::halo`] pixels and passed to `f` as a [`Neighbourhood`]. `f` must return one
+/// buffer per output band, of the size of [`Neighbourhood::window`], which is written to the
+/// same region of the outputs.
+///
+/// All the input and output bands must have the same size. The inputs are converted to `T`,
+/// and the results from `U` to the type of the outputs.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::{process_chunks, Buffer, ChunkOptions};
+/// use gdal::{Dataset, DriverManager};
+///
+/// // 3x3 mean filter, ignoring nodata
+/// let dataset = Dataset::open("fixtures/tinymarble.tif")?;
+/// let (cols, rows) = dataset.raster_size();
+/// let driver = DriverManager::get_driver_by_name("MEM")?;
+/// let output = driver.create_with_band_type::<f32, _>("", cols, rows, 1)?;
+/// let options = ChunkOptions {
+///     chunk_size: Some((64, 64)),
+///     halo: 1,
+///     ..Default::default()
+/// };
+/// process_chunks::<f32, f32, _>(
+///     &[dataset.rasterband(1)?],
+///     &mut [output.rasterband(1)?],
+///     &options,
+///     |chunk| {
+///         let (cols, rows) = chunk.window().size;
+///         let mut data = Vec::with_capacity(cols * rows);
+///         for y in 0..rows as isize {
+///             for x in 0..cols as isize {
+///                 let values = (-1..=1)
+///                     .flat_map(|dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+///                     .filter_map(|pixel| chunk.value(0, pixel))
+///                     .collect::<Vec<_>>();
+///                 data.push(values.iter().sum::<f32>() / values.len() as f32);
+///             }
+///         }
+///         Ok(vec![Buffer::new((cols, rows), data)])
+///     },
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn process_chunks<T, U, F>(
+    inputs: &[RasterBand],
+    outputs: &mut [RasterBand],
+    options: &ChunkOptions,
+    mut f: F,
+) -> Result<()>
+where
+    T: Copy + GdalType + PartialEq,
+    U: Copy + GdalType,
+    F: FnMut(&Neighbourhood<T>) -> Result<Vec<Buffer<U>>>,
+{
+    let reader = ChunkReader::<T>::new(inputs, outputs, options)?;
+    for window in reader.windows() {
+        let chunk = reader.read(inputs, window)?;
+        write_chunk(outputs, window, f(&chunk)?)?;
+    }
+    Ok(())
+}
+
+/// Like [`process_chunks`], but runs `f` on several chunks in parallel, on the rayon thread
+/// pool.
+///
+/// The chunks are still read and written from the calling thread, in batches of one chunk per
+/// thread, so the bands can belong to any dataset, not only one opened in thread-safe mode.
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn par_process_chunks<T, U, F>(
+    inputs: &[RasterBand],
+    outputs: &mut [RasterBand],
+    options: &ChunkOptions,
+    f: F,
+) -> Result<()>
+where
+    T: Copy + GdalType + PartialEq + Send + Sync,
+    U: Copy + GdalType + Send,
+    F: Fn(&Neighbourhood<T>) -> Result<Vec<Buffer<U>>> + Sync,
+{
+    let reader = ChunkReader::<T>::new(inputs, outputs, options)?;
+    let windows = reader.windows().collect::<Vec<_>>();
+    for batch in windows.chunks(rayon::current_num_threads()) {
+        let chunks = batch
+            .iter()
+            .map(|&window| reader.read(inputs, window))
+            .collect::<Result<Vec<_>>>()?;
+        let results = chunks.par_iter().map(&f).collect::<Vec<_>>();
+        for (&window, buffers) in batch.iter().zip(results) {
+            write_chunk(outputs, window, buffers?)?;
+        }
+    }
+    Ok(())
+}
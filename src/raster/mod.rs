@@ -74,26 +74,39 @@
 //!     ...
 //! ```
 
-pub use buffer::{Buffer, ByteBuffer};
+pub use blocks::{Block, BlockIter, Window};
+pub use buffer::{Buffer, Buffer3, ByteBuffer, Interleaving};
+#[cfg(feature = "rayon")]
+pub use chunks::par_process_chunks;
+pub use chunks::{process_chunks, ChunkOptions, Neighbourhood};
 pub use create_options::RasterCreationOptions;
 pub use mdarray::{
     Attribute, Dimension, ExtendedDataType, ExtendedDataTypeClass, Group, MDArray, MdStatisticsAll,
 };
 pub use rasterband::{
-    CmykEntry, ColorEntry, ColorInterpretation, ColorTable, GrayEntry, Histogram, HlsEntry,
-    PaletteInterpretation, RasterBand, ResampleAlg, RgbaEntry, StatisticsAll, StatisticsMinMax,
+    CmykEntry, ColorEntry, ColorInterpretation, ColorTable, ComputedStatistics, DataCoverageFlags,
+    GrayEntry, Histogram, HlsEntry, PaletteInterpretation, RasterBand, ResampleAlg, RgbaEntry,
+    StatisticsAll, StatisticsMinMax,
 };
 pub use rasterize::{rasterize, BurnSource, MergeAlgorithm, OptimizeMode, RasterizeOptions};
+pub use rat::{RasterAttributeTable, RatFieldType, RatFieldUsage, RatTableType};
+pub use transformer::{Transformer, TransformerMethod, TransformerOptions};
 pub use types::{AdjustedValue, GdalDataType, GdalType};
-pub use warp::reproject;
+pub use vrt::{register_pixel_function, PixelFunction, VrtBand, VrtDataset, VrtSource};
+pub use warp::{reproject, WarpResampleAlg, WarpedDataset};
 
+mod blocks;
 mod buffer;
+mod chunks;
 mod create_options;
 mod mdarray;
 pub mod processing;
 mod rasterband;
 mod rasterize;
+mod rat;
 #[cfg(test)]
 mod tests;
+mod transformer;
 mod types;
+mod vrt;
 mod warp;
@@ -11,16 +11,17 @@ use gdal_sys::{
     GDALRasterIOExtraArg, GDALSetColorEntry, GDALSetDefaultHistogramEx, GDALSetRasterColorTable,
 };
 
+use crate::cpl::CslStringList;
 use crate::dataset::Dataset;
 use crate::errors::*;
 use crate::gdal_major_object::MajorObject;
 use crate::metadata::Metadata;
-use crate::raster::buffer::Buffer;
+use crate::raster::buffer::{Buffer, Buffer3, Interleaving};
 use crate::raster::ResampleAlg::{
     Average, Bilinear, Cubic, CubicSpline, Gauss, Lanczos, Mode, NearestNeighbour,
 };
-use crate::raster::{GdalDataType, GdalType};
-use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
+use crate::raster::{GdalDataType, GdalType, RasterAttributeTable};
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, _progress_args, _string, _string_array};
 
 /// [Dataset] methods for raster datasets.
 impl Dataset {
@@ -104,6 +105,215 @@ impl Dataset {
         let size_y = unsafe { gdal_sys::GDALGetRasterYSize(self.c_dataset()) } as usize;
         (size_x, size_y)
     }
+
+    /// Compute the statistics of all the bands of this dataset, even if some are already
+    /// cached. See [`RasterBand::compute_statistics`].
+    ///
+    /// `progress` is called with the completed fraction over all bands, and can return
+    /// `false` to cancel the computation.
+    pub fn compute_statistics(
+        &self,
+        is_approx_ok: bool,
+        progress: Option<&mut dyn FnMut(f64) -> bool>,
+    ) -> Result<Vec<ComputedStatistics>> {
+        let mut progress = progress;
+        let band_count = self.raster_count();
+        let mut statistics = Vec::with_capacity(band_count);
+        for (i, band) in self.rasterbands().enumerate() {
+            let band = band?;
+            let mut band_progress;
+            let progress: Option<&mut dyn FnMut(f64) -> bool> = match progress.as_mut() {
+                Some(progress) => {
+                    band_progress =
+                        move |complete: f64| progress((i as f64 + complete) / band_count as f64);
+                    Some(&mut band_progress)
+                }
+                None => None,
+            };
+            statistics.push(band.compute_statistics(is_approx_ok, progress)?);
+        }
+        Ok(statistics)
+    }
+
+    /// Remove the cached statistics of all the bands of this dataset, so that they are
+    /// computed again.
+    ///
+    /// See: [`GDALDatasetClearStatistics`](https://gdal.org/api/raster_c_api.html#_CPPv426GDALDatasetClearStatistics12GDALDatasetH)
+    pub fn clear_statistics(&mut self) {
+        unsafe { gdal_sys::GDALDatasetClearStatistics(self.c_dataset()) };
+    }
+
+    /// Read a [`Buffer3<T>`] from several bands of this dataset at once, where `T` implements
+    /// [`GdalType`].
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size (GDAL will interpolate data if `window_size` != `shape`)
+    /// * `shape` - the desired size of each band of the buffer
+    /// * `bands` - the _1-based_ indices of the bands to read, or empty for all bands
+    /// * `interleaving` - the memory layout of the bands in the buffer
+    /// * `e_resample_alg` - the resample algorithm used for the interpolation. Default: `NearestNeighbor`.
+    ///
+    /// See: [`GDALDatasetRasterIOEx`](https://gdal.org/api/raster_c_api.html#_CPPv421GDALDatasetRasterIOEx12GDALDatasetH10GDALRWFlagiiiiPvii12GDALDataTypeiPKi10GSpacing10GSpacing10GSpacingP20GDALRasterIOExtraArg)
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::Dataset;
+    /// use gdal::raster::Interleaving;
+    /// let dataset = Dataset::open("fixtures/tinymarble.tif")?;
+    /// let size = dataset.raster_size();
+    /// let rgb = dataset.read_as::<u8>((0, 0), size, size, &[1, 2, 3], Interleaving::Pixel, None)?;
+    /// assert_eq!(rgb.shape(), (size.0, size.1, 3));
+    /// // RGBRGB...
+    /// assert_eq!(rgb.data()[..3], [rgb[(0, 0, 0)], rgb[(1, 0, 0)], rgb[(2, 0, 0)]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_as<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        shape: (usize, usize),
+        bands: &[usize],
+        interleaving: Interleaving,
+        e_resample_alg: Option<ResampleAlg>,
+    ) -> Result<Buffer3<T>> {
+        let mut band_map = self.band_map(bands)?;
+        let shape = (shape.0, shape.1, band_map.len());
+        let len = shape.0 * shape.1 * shape.2;
+        let mut data: Vec<T> = Vec::with_capacity(len);
+
+        // Safety: the GDALDatasetRasterIOEx writes
+        // exactly len elements into the slice, before we
+        // read from this slice.
+        unsafe {
+            self.raster_io::<T>(
+                GDALRWFlag::GF_Read,
+                window,
+                window_size,
+                shape,
+                &mut band_map,
+                interleaving,
+                data.as_mut_ptr() as *mut c_void,
+                e_resample_alg,
+            )?;
+            data.set_len(len);
+        }
+
+        Ok(Buffer3::new(shape, interleaving, data))
+    }
+
+    /// Write a [`Buffer3<T>`] into several bands of this dataset at once, using the memory
+    /// layout given by [`Buffer3::interleaving`].
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size (GDAL will interpolate data if window_size != Buffer3.size)
+    /// * `bands` - the _1-based_ indices of the bands to write, or empty for all bands; there
+    ///   must be as many as the bands of `buffer`
+    /// * `buffer` - the data to write into the window
+    ///
+    /// # Notes
+    ///
+    /// Some drivers might temporarily modify the content of `buffer`, e.g. to do byte
+    /// swapping. Therefore a `&mut` parameter is required.
+    ///
+    /// See: [`GDALDatasetRasterIOEx`](https://gdal.org/api/raster_c_api.html#_CPPv421GDALDatasetRasterIOEx12GDALDatasetH10GDALRWFlagiiiiPvii12GDALDataTypeiPKi10GSpacing10GSpacing10GSpacingP20GDALRasterIOExtraArg)
+    pub fn write<T: GdalType + Copy>(
+        &mut self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        bands: &[usize],
+        buffer: &mut Buffer3<T>,
+    ) -> Result<()> {
+        let mut band_map = self.band_map(bands)?;
+        let shape = buffer.shape();
+        if band_map.len() != shape.2 {
+            return Err(GdalError::BadArgument(format!(
+                "buffer has {} bands, but {} bands were requested",
+                shape.2,
+                band_map.len()
+            )));
+        }
+
+        unsafe {
+            self.raster_io::<T>(
+                GDALRWFlag::GF_Write,
+                window,
+                window_size,
+                shape,
+                &mut band_map,
+                buffer.interleaving(),
+                buffer.data_mut().as_mut_ptr() as *mut c_void,
+                None,
+            )
+        }
+    }
+
+    /// Returns the band indices to pass to `GDALDatasetRasterIOEx`: `bands`, or all bands if
+    /// empty.
+    fn band_map(&self, bands: &[usize]) -> Result<Vec<c_int>> {
+        if bands.is_empty() {
+            (1..=self.raster_count())
+                .map(|b| Ok(b.try_into()?))
+                .collect()
+        } else {
+            bands.iter().map(|&b| Ok(b.try_into()?)).collect()
+        }
+    }
+
+    /// # Safety
+    /// `data` must point to `shape.0 * shape.1 * shape.2` elements of type `T`.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn raster_io<T: GdalType>(
+        &self,
+        rw_flag: GDALRWFlag::Type,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        shape: (usize, usize, usize),
+        band_map: &mut [c_int],
+        interleaving: Interleaving,
+        data: *mut c_void,
+        e_resample_alg: Option<ResampleAlg>,
+    ) -> Result<()> {
+        let resample_alg = e_resample_alg.unwrap_or(ResampleAlg::NearestNeighbour);
+
+        let mut options: GDALRasterIOExtraArg = RasterIOExtraArg {
+            e_resample_alg: resample_alg,
+            ..Default::default()
+        }
+        .into();
+
+        let options_ptr: *mut GDALRasterIOExtraArg = &mut options;
+
+        let element_size = std::mem::size_of::<T>() as i64;
+        let (pixel_space, line_space, band_space) = interleaving.strides(shape);
+
+        let rv = gdal_sys::GDALDatasetRasterIOEx(
+            self.c_dataset(),
+            rw_flag,
+            window.0.try_into()?,
+            window.1.try_into()?,
+            window_size.0.try_into()?,
+            window_size.1.try_into()?,
+            data,
+            shape.0.try_into()?,
+            shape.1.try_into()?,
+            T::gdal_ordinal(),
+            band_map.len().try_into()?,
+            band_map.as_mut_ptr(),
+            pixel_space as i64 * element_size,
+            line_space as i64 * element_size,
+            band_space as i64 * element_size,
+            options_ptr,
+        );
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
 }
 
 /// Resampling algorithms used throughout various GDAL raster I/O operations.
@@ -228,6 +438,21 @@ impl GdalMaskFlags {
     }
 }
 
+bitflags::bitflags! {
+    /// Data coverage of a region of a [`RasterBand`], as returned by
+    /// [`RasterBand::data_coverage`].
+    #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+    pub struct DataCoverageFlags: c_int {
+        /// The driver doesn't know the coverage, and the region is assumed to have data.
+        const UNIMPLEMENTED = 0x01;
+        /// The region contains data, i.e. pixels that are not nodata or masked.
+        const DATA = 0x02;
+        /// The region contains empty areas, e.g. missing blocks of sparse files, that would
+        /// read as nodata or zero.
+        const EMPTY = 0x04;
+    }
+}
+
 /// Extra options used to read a raster.
 ///
 /// For documentation, see `gdal_sys::GDALRasterIOExtraArg`.
@@ -692,6 +917,39 @@ impl<'a> RasterBand<'a> {
         Ok(())
     }
 
+    /// Returns whether the given window of this band contains data or empty areas, e.g. to
+    /// skip the missing blocks of sparse files, and the percentage of the window covered by
+    /// data.
+    ///
+    /// This only uses the layout of the file and doesn't read pixel values, so blocks
+    /// explicitly filled with nodata are reported as data. Drivers that cannot tell return
+    /// [`DataCoverageFlags::UNIMPLEMENTED`] `|` [`DataCoverageFlags::DATA`] and 100%.
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size
+    ///
+    /// See: [`GDALGetDataCoverageStatus`](https://gdal.org/api/raster_c_api.html#_CPPv425GDALGetDataCoverageStatus15GDALRasterBandHiiiiiPd)
+    pub fn data_coverage(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+    ) -> Result<(DataCoverageFlags, f64)> {
+        let mut data_pct = 0.0;
+        let flags = unsafe {
+            gdal_sys::GDALGetDataCoverageStatus(
+                self.c_rasterband,
+                window.0.try_into()?,
+                window.1.try_into()?,
+                window_size.0.try_into()?,
+                window_size.1.try_into()?,
+                0,
+                &mut data_pct,
+            )
+        };
+        Ok((DataCoverageFlags::from_bits_retain(flags), data_pct))
+    }
+
     /// Returns the pixel datatype of this band.
     pub fn band_type(&self) -> GdalDataType {
         let ordinal = unsafe { gdal_sys::GDALGetRasterDataType(self.c_rasterband) };
@@ -872,6 +1130,34 @@ impl<'a> RasterBand<'a> {
         unsafe { GDALSetRasterColorTable(self.c_rasterband, colors.c_color_table) };
     }
 
+    /// Returns the default [`RasterAttributeTable`] of this band, if any.
+    ///
+    /// The table belongs to the band; see [`RasterAttributeTable`] for whether changes to it
+    /// are saved.
+    ///
+    /// See: [`GDALGetDefaultRAT`](https://gdal.org/api/raster_c_api.html#_CPPv417GDALGetDefaultRAT15GDALRasterBandH)
+    pub fn default_rat(&self) -> Option<RasterAttributeTable<'_>> {
+        let c_rat = unsafe { gdal_sys::GDALGetDefaultRAT(self.c_rasterband) };
+        if c_rat.is_null() {
+            return None;
+        }
+        Some(unsafe { RasterAttributeTable::from_c_rat(c_rat) })
+    }
+
+    /// Sets the default [`RasterAttributeTable`] of this band, which copies `rat`.
+    ///
+    /// Not all formats support raster attribute tables, but the table is also saved to the
+    /// `.aux.xml` file when possible.
+    ///
+    /// See: [`GDALSetDefaultRAT`](https://gdal.org/api/raster_c_api.html#_CPPv417GDALSetDefaultRAT15GDALRasterBandH25GDALRasterAttributeTableH)
+    pub fn set_default_rat(&mut self, rat: &RasterAttributeTable) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALSetDefaultRAT(self.c_rasterband, rat.c_rat()) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
     /// Returns the scale of this band if set.
     pub fn scale(&self) -> Option<f64> {
         let mut pb_success = 1;
@@ -949,6 +1235,15 @@ impl<'a> RasterBand<'a> {
         }
     }
 
+    /// Returns `true` if this band can compute overviews of any resolution on the fly,
+    /// as is the case e.g. for some web services, in which case [`RasterBand::overview`] is
+    /// not needed to read at a lower resolution.
+    ///
+    /// See: [`GDALHasArbitraryOverviews`](https://gdal.org/api/raster_c_api.html#_CPPv425GDALHasArbitraryOverviews15GDALRasterBandH)
+    pub fn has_arbitrary_overviews(&self) -> bool {
+        unsafe { gdal_sys::GDALHasArbitraryOverviews(self.c_rasterband) != 0 }
+    }
+
     /// Return the unit of the rasterband.
     /// If there is no unit, the empty string is returned.
     pub fn unit(&self) -> String {
@@ -956,6 +1251,45 @@ impl<'a> RasterBand<'a> {
         _string(c_ptr).unwrap_or_default()
     }
 
+    /// Set the unit of the rasterband, e.g. `"m"` or `"ft"` for elevations.
+    /// The empty string clears the unit.
+    ///
+    /// See: [`GDALSetRasterUnitType`](https://gdal.org/api/raster_c_api.html#_CPPv421GDALSetRasterUnitType15GDALRasterBandHPKc)
+    pub fn set_unit(&mut self, unit: &str) -> Result<()> {
+        let c_unit = CString::new(unit)?;
+        let rv = unsafe { gdal_sys::GDALSetRasterUnitType(self.c_rasterband, c_unit.as_ptr()) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Return the names of the categories of a classified band: the name at index `i` is the
+    /// name of the pixel value `i`. Unnamed values have an empty name.
+    ///
+    /// See: [`GDALGetRasterCategoryNames`](https://gdal.org/api/raster_c_api.html#_CPPv426GDALGetRasterCategoryNames15GDALRasterBandH)
+    pub fn category_names(&self) -> Vec<String> {
+        let c_names = unsafe { gdal_sys::GDALGetRasterCategoryNames(self.c_rasterband) };
+        _string_array(c_names)
+    }
+
+    /// Set the names of the categories of a classified band: the name at index `i` is the
+    /// name of the pixel value `i`. An empty list clears the names.
+    ///
+    /// See: [`GDALSetRasterCategoryNames`](https://gdal.org/api/raster_c_api.html#_CPPv426GDALSetRasterCategoryNames15GDALRasterBandH12CSLConstList)
+    pub fn set_category_names<S: AsRef<str>>(&mut self, names: &[S]) -> Result<()> {
+        let mut c_names = CslStringList::new();
+        for name in names {
+            c_names.add_string(name.as_ref())?;
+        }
+        let rv =
+            unsafe { gdal_sys::GDALSetRasterCategoryNames(self.c_rasterband, c_names.as_ptr()) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
     /// Read the band mask flags for a GDAL `RasterBand`.
     pub fn mask_flags(&self) -> Result<GdalMaskFlags> {
         let band_mask_flags = unsafe { gdal_sys::GDALGetMaskFlags(self.c_rasterband) };
@@ -1030,6 +1364,105 @@ impl<'a> RasterBand<'a> {
         }
     }
 
+    /// Compute image statistics, even if some are already cached.
+    ///
+    /// Returns the minimum, maximum, mean and standard deviation of the valid pixel values in
+    /// this band, and the percentage of valid pixels. If `is_approx_ok` is `true`, overviews
+    /// or a subset of the image tiles may be used.
+    ///
+    /// `progress` is called with the completed fraction, and can return `false` to cancel
+    /// the computation.
+    ///
+    /// The statistics are stored as band metadata, and saved in the `.aux.xml` file of formats
+    /// using PAM (Persistent Auxiliary Metadata) when the dataset is closed or on
+    /// [`Dataset::flush_cache`].
+    ///
+    /// This methods is a wrapper for [`GDALComputeRasterStatistics`](https://gdal.org/api/gdalrasterband_cpp.html#_CPPv4N14GDALRasterBand17ComputeStatisticsEiPdPdPdPd16GDALProgressFuncPv).
+    pub fn compute_statistics(
+        &self,
+        is_approx_ok: bool,
+        progress: Option<&mut dyn FnMut(f64) -> bool>,
+    ) -> Result<ComputedStatistics> {
+        let mut statistics = ComputedStatistics {
+            min: 0.,
+            max: 0.,
+            mean: 0.,
+            std_dev: 0.,
+            valid_percent: None,
+        };
+
+        let mut progress = progress;
+        let (pfn_progress, p_progress_data) = _progress_args(&mut progress);
+        let rv = unsafe {
+            gdal_sys::GDALComputeRasterStatistics(
+                self.c_rasterband,
+                c_int::from(is_approx_ok),
+                &mut statistics.min,
+                &mut statistics.max,
+                &mut statistics.mean,
+                &mut statistics.std_dev,
+                pfn_progress,
+                p_progress_data,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+
+        statistics.valid_percent = self
+            .metadata_item("STATISTICS_VALID_PERCENT", "")
+            .and_then(|v| v.parse().ok());
+        Ok(statistics)
+    }
+
+    /// Set the statistics of this band, e.g. after computing them from the data in a
+    /// different way.
+    ///
+    /// Like [`RasterBand::compute_statistics`], the statistics are stored as band metadata.
+    ///
+    /// This methods is a wrapper for [`GDALSetRasterStatistics`](https://gdal.org/api/gdalrasterband_cpp.html#_CPPv4N14GDALRasterBand13SetStatisticsEdddd).
+    pub fn set_statistics(&mut self, statistics: &StatisticsAll) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::GDALSetRasterStatistics(
+                self.c_rasterband,
+                statistics.min,
+                statistics.max,
+                statistics.mean,
+                statistics.std_dev,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Remove the cached statistics of this band, i.e. the `STATISTICS_*` metadata items,
+    /// so that [`RasterBand::get_statistics`] computes them again.
+    ///
+    /// See also [`Dataset::clear_statistics`].
+    pub fn clear_statistics(&mut self) -> Result<()> {
+        let keys = self
+            .metadata()
+            .filter(|entry| entry.is_default_domain() && entry.key.starts_with("STATISTICS_"))
+            .map(|entry| CString::new(entry.key))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        for key in keys {
+            let rv = unsafe {
+                gdal_sys::GDALSetMetadataItem(
+                    self.c_rasterband as GDALMajorObjectH,
+                    key.as_ptr(),
+                    std::ptr::null(),
+                    std::ptr::null(),
+                )
+            };
+            if rv != CPLErr::CE_None {
+                return Err(_last_cpl_err(rv));
+            }
+        }
+        Ok(())
+    }
+
     /// Compute the min/max values for a band.
     ///
     /// If `is_approx_ok` is `true`, then the band’s GetMinimum()/GetMaximum() will be trusted.
@@ -1202,7 +1635,7 @@ pub struct StatisticsMinMax {
     pub max: f64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct StatisticsAll {
     pub min: f64,
     pub max: f64,
@@ -1210,6 +1643,29 @@ pub struct StatisticsAll {
     pub std_dev: f64,
 }
 
+/// Statistics computed by [`RasterBand::compute_statistics`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComputedStatistics {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+    /// Percentage of valid (not nodata or masked) pixels, as stored by GDAL in the
+    /// `STATISTICS_VALID_PERCENT` metadata item.
+    pub valid_percent: Option<f64>,
+}
+
+impl From<ComputedStatistics> for StatisticsAll {
+    fn from(statistics: ComputedStatistics) -> Self {
+        StatisticsAll {
+            min: statistics.min,
+            max: statistics.max,
+            mean: statistics.mean,
+            std_dev: statistics.std_dev,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Histogram {
     min: f64,
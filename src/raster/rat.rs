@@ -0,0 +1,752 @@
+use std::ffi::{c_char, c_int, CString};
+use std::marker::PhantomData;
+
+use gdal_sys::{
+    CPLErr, GDALRATFieldType, GDALRATFieldUsage, GDALRATTableType, GDALRWFlag,
+    GDALRasterAttributeTableH,
+};
+
+use crate::errors::*;
+use crate::raster::RasterBand;
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
+
+/// Data type of a [`RasterAttributeTable`] column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RatFieldType {
+    /// Integer field
+    Integer,
+    /// Floating point (double) field
+    Real,
+    /// String field
+    String,
+    /// Boolean field (GDAL 3.12+)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_12)))]
+    Boolean,
+    /// Date/time field (GDAL 3.12+)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_12)))]
+    DateTime,
+    /// Geometry field, as WKB (GDAL 3.12+)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_12)))]
+    WkbGeometry,
+}
+
+impl RatFieldType {
+    /// Creates a field type from its C API int value.
+    pub fn from_c_int(field_type: GDALRATFieldType::Type) -> Option<Self> {
+        match field_type {
+            GDALRATFieldType::GFT_Integer => Some(Self::Integer),
+            GDALRATFieldType::GFT_Real => Some(Self::Real),
+            GDALRATFieldType::GFT_String => Some(Self::String),
+            #[cfg(any(major_ge_4, all(major_is_3, minor_ge_12)))]
+            GDALRATFieldType::GFT_Boolean => Some(Self::Boolean),
+            #[cfg(any(major_ge_4, all(major_is_3, minor_ge_12)))]
+            GDALRATFieldType::GFT_DateTime => Some(Self::DateTime),
+            #[cfg(any(major_ge_4, all(major_is_3, minor_ge_12)))]
+            GDALRATFieldType::GFT_WKBGeometry => Some(Self::WkbGeometry),
+            _ => None,
+        }
+    }
+
+    /// Returns the C API int value of this field type.
+    pub fn c_int(&self) -> GDALRATFieldType::Type {
+        match self {
+            Self::Integer => GDALRATFieldType::GFT_Integer,
+            Self::Real => GDALRATFieldType::GFT_Real,
+            Self::String => GDALRATFieldType::GFT_String,
+            #[cfg(any(major_ge_4, all(major_is_3, minor_ge_12)))]
+            Self::Boolean => GDALRATFieldType::GFT_Boolean,
+            #[cfg(any(major_ge_4, all(major_is_3, minor_ge_12)))]
+            Self::DateTime => GDALRATFieldType::GFT_DateTime,
+            #[cfg(any(major_ge_4, all(major_is_3, minor_ge_12)))]
+            Self::WkbGeometry => GDALRATFieldType::GFT_WKBGeometry,
+        }
+    }
+}
+
+/// Intended use of a [`RasterAttributeTable`] column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RatFieldUsage {
+    /// General purpose field
+    Generic,
+    /// Histogram pixel count
+    PixelCount,
+    /// Class name
+    Name,
+    /// Class range minimum
+    Min,
+    /// Class range maximum
+    Max,
+    /// Class value (min = max)
+    MinMax,
+    /// Red class color (0-255)
+    Red,
+    /// Green class color (0-255)
+    Green,
+    /// Blue class color (0-255)
+    Blue,
+    /// Alpha (0 = transparent, 255 = opaque)
+    Alpha,
+    /// Color range red minimum
+    RedMin,
+    /// Color range green minimum
+    GreenMin,
+    /// Color range blue minimum
+    BlueMin,
+    /// Color range alpha minimum
+    AlphaMin,
+    /// Color range red maximum
+    RedMax,
+    /// Color range green maximum
+    GreenMax,
+    /// Color range blue maximum
+    BlueMax,
+    /// Color range alpha maximum
+    AlphaMax,
+}
+
+impl RatFieldUsage {
+    /// Creates a field usage from its C API int value.
+    pub fn from_c_int(usage: GDALRATFieldUsage::Type) -> Option<Self> {
+        match usage {
+            GDALRATFieldUsage::GFU_Generic => Some(Self::Generic),
+            GDALRATFieldUsage::GFU_PixelCount => Some(Self::PixelCount),
+            GDALRATFieldUsage::GFU_Name => Some(Self::Name),
+            GDALRATFieldUsage::GFU_Min => Some(Self::Min),
+            GDALRATFieldUsage::GFU_Max => Some(Self::Max),
+            GDALRATFieldUsage::GFU_MinMax => Some(Self::MinMax),
+            GDALRATFieldUsage::GFU_Red => Some(Self::Red),
+            GDALRATFieldUsage::GFU_Green => Some(Self::Green),
+            GDALRATFieldUsage::GFU_Blue => Some(Self::Blue),
+            GDALRATFieldUsage::GFU_Alpha => Some(Self::Alpha),
+            GDALRATFieldUsage::GFU_RedMin => Some(Self::RedMin),
+            GDALRATFieldUsage::GFU_GreenMin => Some(Self::GreenMin),
+            GDALRATFieldUsage::GFU_BlueMin => Some(Self::BlueMin),
+            GDALRATFieldUsage::GFU_AlphaMin => Some(Self::AlphaMin),
+            GDALRATFieldUsage::GFU_RedMax => Some(Self::RedMax),
+            GDALRATFieldUsage::GFU_GreenMax => Some(Self::GreenMax),
+            GDALRATFieldUsage::GFU_BlueMax => Some(Self::BlueMax),
+            GDALRATFieldUsage::GFU_AlphaMax => Some(Self::AlphaMax),
+            _ => None,
+        }
+    }
+
+    /// Returns the C API int value of this field usage.
+    pub fn c_int(&self) -> GDALRATFieldUsage::Type {
+        match self {
+            Self::Generic => GDALRATFieldUsage::GFU_Generic,
+            Self::PixelCount => GDALRATFieldUsage::GFU_PixelCount,
+            Self::Name => GDALRATFieldUsage::GFU_Name,
+            Self::Min => GDALRATFieldUsage::GFU_Min,
+            Self::Max => GDALRATFieldUsage::GFU_Max,
+            Self::MinMax => GDALRATFieldUsage::GFU_MinMax,
+            Self::Red => GDALRATFieldUsage::GFU_Red,
+            Self::Green => GDALRATFieldUsage::GFU_Green,
+            Self::Blue => GDALRATFieldUsage::GFU_Blue,
+            Self::Alpha => GDALRATFieldUsage::GFU_Alpha,
+            Self::RedMin => GDALRATFieldUsage::GFU_RedMin,
+            Self::GreenMin => GDALRATFieldUsage::GFU_GreenMin,
+            Self::BlueMin => GDALRATFieldUsage::GFU_BlueMin,
+            Self::AlphaMin => GDALRATFieldUsage::GFU_AlphaMin,
+            Self::RedMax => GDALRATFieldUsage::GFU_RedMax,
+            Self::GreenMax => GDALRATFieldUsage::GFU_GreenMax,
+            Self::BlueMax => GDALRATFieldUsage::GFU_BlueMax,
+            Self::AlphaMax => GDALRATFieldUsage::GFU_AlphaMax,
+        }
+    }
+}
+
+/// Kind of values described by a [`RasterAttributeTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RatTableType {
+    /// Rows describe discrete classes, e.g. land-cover categories.
+    Thematic,
+    /// Rows describe ranges of continuous values.
+    Athematic,
+}
+
+impl RatTableType {
+    /// Creates a table type from its C API int value.
+    pub fn from_c_int(table_type: GDALRATTableType::Type) -> Option<Self> {
+        match table_type {
+            GDALRATTableType::GRTT_THEMATIC => Some(Self::Thematic),
+            GDALRATTableType::GRTT_ATHEMATIC => Some(Self::Athematic),
+            _ => None,
+        }
+    }
+
+    /// Returns the C API int value of this table type.
+    pub fn c_int(&self) -> GDALRATTableType::Type {
+        match self {
+            Self::Thematic => GDALRATTableType::GRTT_THEMATIC,
+            Self::Athematic => GDALRATTableType::GRTT_ATHEMATIC,
+        }
+    }
+}
+
+/// Raster Attribute Table, associating attributes (class names, colors, pixel counts, …) with
+/// the pixel values of a [`RasterBand`].
+///
+/// Each row describes a pixel value, or a range of values, either through [`RatFieldUsage::MinMax`]
+/// (or [`RatFieldUsage::Min`] and [`RatFieldUsage::Max`]) columns, or through
+/// [linear binning](RasterAttributeTable::set_linear_binning).
+///
+/// A table read with [`RasterBand::default_rat`] belongs to the band. Whether changes made
+/// to it are saved depends on the driver, see
+/// [`RasterAttributeTable::changes_are_written_to_file`]; otherwise, use
+/// [`RasterBand::set_default_rat`].
+///
+/// See: [`GDALRasterAttributeTable`](https://gdal.org/api/gdalrasterattributetable_cpp.html)
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use gdal::DriverManager;
+/// use gdal::raster::{RasterAttributeTable, RatFieldType, RatFieldUsage};
+/// # fn main() -> gdal::errors::Result<()> {
+/// let driver = DriverManager::get_driver_by_name("MEM")?;
+/// let ds = driver.create("", 10, 10, 1)?;
+/// let mut band = ds.rasterband(1)?;
+///
+/// let mut rat = RasterAttributeTable::new();
+/// rat.create_column("value", RatFieldType::Integer, RatFieldUsage::MinMax)?;
+/// rat.create_column("class", RatFieldType::String, RatFieldUsage::Name)?;
+/// rat.set_row_count(2);
+/// rat.write_column_as_int(0, 0, &[1, 2])?;
+/// rat.write_column_as_string(1, 0, &["forest", "water"])?;
+/// band.set_default_rat(&rat)?;
+///
+/// let rat = band.default_rat().unwrap();
+/// let row = rat.row_of_value(2.0).unwrap();
+/// assert_eq!(rat.value_as_string(row, 1)?, "water");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RasterAttributeTable<'a> {
+    c_rat: GDALRasterAttributeTableH,
+    /// If `true`, Rust is responsible for deallocating the table pointed to by `c_rat`,
+    /// which is the case when instantiated directly, as opposed to when read via
+    /// [`RasterBand::default_rat`].
+    rust_owned: bool,
+    phantom_raster_band: PhantomData<&'a RasterBand<'a>>,
+}
+
+impl RasterAttributeTable<'_> {
+    /// Creates a new, empty, raster attribute table.
+    ///
+    /// See: [`GDALCreateRasterAttributeTable`](https://gdal.org/api/raster_c_api.html#_CPPv430GDALCreateRasterAttributeTablev)
+    pub fn new() -> Self {
+        let c_rat = unsafe { gdal_sys::GDALCreateRasterAttributeTable() };
+        Self {
+            c_rat,
+            rust_owned: true,
+            phantom_raster_band: PhantomData,
+        }
+    }
+
+    /// Wrap a C raster attribute table owned by a band.
+    ///
+    /// # Safety
+    /// `c_rat` must be a valid pointer that outlives the returned value.
+    pub(crate) unsafe fn from_c_rat(c_rat: GDALRasterAttributeTableH) -> Self {
+        Self {
+            c_rat,
+            rust_owned: false,
+            phantom_raster_band: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_rat(&self) -> GDALRasterAttributeTableH {
+        self.c_rat
+    }
+
+    /// Returns whether the rows describe discrete classes or ranges of continuous values.
+    pub fn table_type(&self) -> Option<RatTableType> {
+        RatTableType::from_c_int(unsafe { gdal_sys::GDALRATGetTableType(self.c_rat) })
+    }
+
+    /// Sets whether the rows describe discrete classes or ranges of continuous values.
+    pub fn set_table_type(&mut self, table_type: RatTableType) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALRATSetTableType(self.c_rat, table_type.c_int()) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if changes to this table are saved to the dataset it was read from.
+    ///
+    /// See: [`GDALRATChangesAreWrittenToFile`](https://gdal.org/api/raster_c_api.html#_CPPv430GDALRATChangesAreWrittenToFile25GDALRasterAttributeTableH)
+    pub fn changes_are_written_to_file(&self) -> bool {
+        unsafe { gdal_sys::GDALRATChangesAreWrittenToFile(self.c_rat) != 0 }
+    }
+
+    /// Returns the number of columns.
+    pub fn column_count(&self) -> usize {
+        unsafe { gdal_sys::GDALRATGetColumnCount(self.c_rat) as usize }
+    }
+
+    /// Returns the name of the column at the 0-based index `col`, or `None` if out of range.
+    pub fn column_name(&self, col: usize) -> Option<String> {
+        let col = self.column_c_int(col)?;
+        _string(unsafe { gdal_sys::GDALRATGetNameOfCol(self.c_rat, col) })
+    }
+
+    /// Returns the data type of the column at the 0-based index `col`, or `None` if out of
+    /// range.
+    pub fn column_type(&self, col: usize) -> Option<RatFieldType> {
+        let col = self.column_c_int(col)?;
+        RatFieldType::from_c_int(unsafe { gdal_sys::GDALRATGetTypeOfCol(self.c_rat, col) })
+    }
+
+    /// Returns the usage of the column at the 0-based index `col`, or `None` if out of range.
+    pub fn column_usage(&self, col: usize) -> Option<RatFieldUsage> {
+        let col = self.column_c_int(col)?;
+        RatFieldUsage::from_c_int(unsafe { gdal_sys::GDALRATGetUsageOfCol(self.c_rat, col) })
+    }
+
+    /// Returns the index of the first column with the given usage.
+    pub fn column_of_usage(&self, usage: RatFieldUsage) -> Option<usize> {
+        let col = unsafe { gdal_sys::GDALRATGetColOfUsage(self.c_rat, usage.c_int()) };
+        col.try_into().ok()
+    }
+
+    /// Returns the index of the first column named `name`.
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        (0..self.column_count()).find(|&col| self.column_name(col).as_deref() == Some(name))
+    }
+
+    /// Appends a column.
+    ///
+    /// See: [`GDALRATCreateColumn`](https://gdal.org/api/raster_c_api.html#_CPPv419GDALRATCreateColumn25GDALRasterAttributeTableHPKc16GDALRATFieldType17GDALRATFieldUsage)
+    pub fn create_column(
+        &mut self,
+        name: &str,
+        field_type: RatFieldType,
+        usage: RatFieldUsage,
+    ) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let rv = unsafe {
+            gdal_sys::GDALRATCreateColumn(
+                self.c_rat,
+                c_name.as_ptr(),
+                field_type.c_int(),
+                usage.c_int(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Returns the number of rows.
+    pub fn row_count(&self) -> usize {
+        unsafe { gdal_sys::GDALRATGetRowCount(self.c_rat) as usize }
+    }
+
+    /// Resizes the table to `count` rows. New cells are zero or empty.
+    pub fn set_row_count(&mut self, count: usize) {
+        let count = c_int::try_from(count).unwrap_or(c_int::MAX);
+        unsafe { gdal_sys::GDALRATSetRowCount(self.c_rat, count) };
+    }
+
+    /// Returns the value of a cell as a string, whatever the type of the column.
+    pub fn value_as_string(&self, row: usize, col: usize) -> Result<String> {
+        let (row, col) = self.cell_c_ints(row, col)?;
+        let rv = unsafe { gdal_sys::GDALRATGetValueAsString(self.c_rat, row, col) };
+        Ok(_string(rv).unwrap_or_default())
+    }
+
+    /// Returns the value of a cell as an integer, whatever the type of the column.
+    pub fn value_as_int(&self, row: usize, col: usize) -> Result<i32> {
+        let (row, col) = self.cell_c_ints(row, col)?;
+        Ok(unsafe { gdal_sys::GDALRATGetValueAsInt(self.c_rat, row, col) })
+    }
+
+    /// Returns the value of a cell as a double, whatever the type of the column.
+    pub fn value_as_double(&self, row: usize, col: usize) -> Result<f64> {
+        let (row, col) = self.cell_c_ints(row, col)?;
+        Ok(unsafe { gdal_sys::GDALRATGetValueAsDouble(self.c_rat, row, col) })
+    }
+
+    /// Sets the value of a cell, converting it to the type of the column.
+    ///
+    /// Setting a value in the row after the last one appends a row.
+    pub fn set_value_as_string(&mut self, row: usize, col: usize, value: &str) -> Result<()> {
+        let (row, col) = self.cell_c_ints(row, col)?;
+        let c_value = CString::new(value)?;
+        self.checked(|c_rat| unsafe {
+            gdal_sys::GDALRATSetValueAsString(c_rat, row, col, c_value.as_ptr())
+        })
+    }
+
+    /// Sets the value of a cell, converting it to the type of the column.
+    ///
+    /// Setting a value in the row after the last one appends a row.
+    pub fn set_value_as_int(&mut self, row: usize, col: usize, value: i32) -> Result<()> {
+        let (row, col) = self.cell_c_ints(row, col)?;
+        self.checked(|c_rat| unsafe { gdal_sys::GDALRATSetValueAsInt(c_rat, row, col, value) })
+    }
+
+    /// Sets the value of a cell, converting it to the type of the column.
+    ///
+    /// Setting a value in the row after the last one appends a row.
+    pub fn set_value_as_double(&mut self, row: usize, col: usize, value: f64) -> Result<()> {
+        let (row, col) = self.cell_c_ints(row, col)?;
+        self.checked(|c_rat| unsafe { gdal_sys::GDALRATSetValueAsDouble(c_rat, row, col, value) })
+    }
+
+    /// Reads `len` values of column `col` as doubles, starting at row `start_row`.
+    ///
+    /// See: [`GDALRATValuesIOAsDouble`](https://gdal.org/api/raster_c_api.html#_CPPv423GDALRATValuesIOAsDouble25GDALRasterAttributeTableH10GDALRWFlagiiiPd)
+    pub fn read_column_as_double(
+        &self,
+        col: usize,
+        start_row: usize,
+        len: usize,
+    ) -> Result<Vec<f64>> {
+        let mut data = vec![0.0; len];
+        let rv = unsafe {
+            gdal_sys::GDALRATValuesIOAsDouble(
+                self.c_rat,
+                GDALRWFlag::GF_Read,
+                col.try_into()?,
+                start_row.try_into()?,
+                len.try_into()?,
+                data.as_mut_ptr(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(data)
+    }
+
+    /// Writes `values` to column `col`, starting at row `start_row`.
+    ///
+    /// See: [`GDALRATValuesIOAsDouble`](https://gdal.org/api/raster_c_api.html#_CPPv423GDALRATValuesIOAsDouble25GDALRasterAttributeTableH10GDALRWFlagiiiPd)
+    pub fn write_column_as_double(
+        &mut self,
+        col: usize,
+        start_row: usize,
+        values: &[f64],
+    ) -> Result<()> {
+        // GDAL doesn't modify the values when writing.
+        let rv = unsafe {
+            gdal_sys::GDALRATValuesIOAsDouble(
+                self.c_rat,
+                GDALRWFlag::GF_Write,
+                col.try_into()?,
+                start_row.try_into()?,
+                values.len().try_into()?,
+                values.as_ptr() as *mut f64,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Reads `len` values of column `col` as integers, starting at row `start_row`.
+    ///
+    /// See: [`GDALRATValuesIOAsInteger`](https://gdal.org/api/raster_c_api.html#_CPPv424GDALRATValuesIOAsInteger25GDALRasterAttributeTableH10GDALRWFlagiiiPi)
+    pub fn read_column_as_int(&self, col: usize, start_row: usize, len: usize) -> Result<Vec<i32>> {
+        let mut data = vec![0; len];
+        let rv = unsafe {
+            gdal_sys::GDALRATValuesIOAsInteger(
+                self.c_rat,
+                GDALRWFlag::GF_Read,
+                col.try_into()?,
+                start_row.try_into()?,
+                len.try_into()?,
+                data.as_mut_ptr(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(data)
+    }
+
+    /// Writes `values` to column `col`, starting at row `start_row`.
+    ///
+    /// See: [`GDALRATValuesIOAsInteger`](https://gdal.org/api/raster_c_api.html#_CPPv424GDALRATValuesIOAsInteger25GDALRasterAttributeTableH10GDALRWFlagiiiPi)
+    pub fn write_column_as_int(
+        &mut self,
+        col: usize,
+        start_row: usize,
+        values: &[i32],
+    ) -> Result<()> {
+        // GDAL doesn't modify the values when writing.
+        let rv = unsafe {
+            gdal_sys::GDALRATValuesIOAsInteger(
+                self.c_rat,
+                GDALRWFlag::GF_Write,
+                col.try_into()?,
+                start_row.try_into()?,
+                values.len().try_into()?,
+                values.as_ptr() as *mut c_int,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Reads `len` values of column `col` as strings, starting at row `start_row`.
+    ///
+    /// See: [`GDALRATValuesIOAsString`](https://gdal.org/api/raster_c_api.html#_CPPv423GDALRATValuesIOAsString25GDALRasterAttributeTableH10GDALRWFlagiii12CSLConstList)
+    pub fn read_column_as_string(
+        &self,
+        col: usize,
+        start_row: usize,
+        len: usize,
+    ) -> Result<Vec<String>> {
+        let mut data: Vec<*mut c_char> = vec![std::ptr::null_mut(); len];
+        let rv = unsafe {
+            gdal_sys::GDALRATValuesIOAsString(
+                self.c_rat,
+                GDALRWFlag::GF_Read,
+                col.try_into()?,
+                start_row.try_into()?,
+                len.try_into()?,
+                data.as_mut_ptr() as _,
+            )
+        };
+        // GDAL allocates each string, even on partial failure.
+        let values = data
+            .into_iter()
+            .map(|ptr| {
+                let value = _string(ptr).unwrap_or_default();
+                unsafe { gdal_sys::VSIFree(ptr as *mut _) };
+                value
+            })
+            .collect();
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(values)
+    }
+
+    /// Writes `values` to column `col`, starting at row `start_row`.
+    ///
+    /// See: [`GDALRATValuesIOAsString`](https://gdal.org/api/raster_c_api.html#_CPPv423GDALRATValuesIOAsString25GDALRasterAttributeTableH10GDALRWFlagiii12CSLConstList)
+    pub fn write_column_as_string<S: AsRef<str>>(
+        &mut self,
+        col: usize,
+        start_row: usize,
+        values: &[S],
+    ) -> Result<()> {
+        let c_values = values
+            .iter()
+            .map(|v| CString::new(v.as_ref()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let mut c_ptrs = c_values.iter().map(|v| v.as_ptr()).collect::<Vec<_>>();
+        let rv = unsafe {
+            gdal_sys::GDALRATValuesIOAsString(
+                self.c_rat,
+                GDALRWFlag::GF_Write,
+                col.try_into()?,
+                start_row.try_into()?,
+                c_ptrs.len().try_into()?,
+                c_ptrs.as_mut_ptr() as _,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Returns the linear binning of this table as `(row0_min, bin_size)`, if any: row `i`
+    /// then covers the values `row0_min + i * bin_size .. row0_min + (i + 1) * bin_size`.
+    pub fn linear_binning(&self) -> Option<(f64, f64)> {
+        let mut row0_min = 0.0;
+        let mut bin_size = 0.0;
+        let rv =
+            unsafe { gdal_sys::GDALRATGetLinearBinning(self.c_rat, &mut row0_min, &mut bin_size) };
+        (rv != 0).then_some((row0_min, bin_size))
+    }
+
+    /// Sets a linear binning, so that row `i` covers the values
+    /// `row0_min + i * bin_size .. row0_min + (i + 1) * bin_size`, instead of using
+    /// [`RatFieldUsage::Min`] and [`RatFieldUsage::Max`] columns.
+    pub fn set_linear_binning(&mut self, row0_min: f64, bin_size: f64) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALRATSetLinearBinning(self.c_rat, row0_min, bin_size) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the row describing the pixel value `value`, or `None` if there
+    /// is none.
+    ///
+    /// See: [`GDALRATGetRowOfValue`](https://gdal.org/api/raster_c_api.html#_CPPv420GDALRATGetRowOfValue25GDALRasterAttributeTableHd)
+    pub fn row_of_value(&self, value: f64) -> Option<usize> {
+        let row = unsafe { gdal_sys::GDALRATGetRowOfValue(self.c_rat, value) };
+        row.try_into().ok()
+    }
+
+    fn column_c_int(&self, col: usize) -> Option<c_int> {
+        if col < self.column_count() {
+            col.try_into().ok()
+        } else {
+            None
+        }
+    }
+
+    fn cell_c_ints(&self, row: usize, col: usize) -> Result<(c_int, c_int)> {
+        let Some(col) = self.column_c_int(col) else {
+            return Err(GdalError::InvalidFieldIndex {
+                index: col,
+                method_name: "GDALRATGetTypeOfCol",
+            });
+        };
+        Ok((row.try_into()?, col))
+    }
+
+    /// Calls `f`, which doesn't return an error code, and checks the last CPL error.
+    fn checked(&mut self, f: impl FnOnce(GDALRasterAttributeTableH)) -> Result<()> {
+        unsafe { gdal_sys::CPLErrorReset() };
+        f(self.c_rat);
+        let rv = unsafe { gdal_sys::CPLGetLastErrorType() };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+}
+
+impl Default for RasterAttributeTable<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for RasterAttributeTable<'_> {
+    /// Creates an independent copy of this table.
+    fn clone(&self) -> Self {
+        let c_rat = unsafe { gdal_sys::GDALRATClone(self.c_rat) };
+        if c_rat.is_null() {
+            panic!("{}", _last_null_pointer_err("GDALRATClone"));
+        }
+        Self {
+            c_rat,
+            rust_owned: true,
+            phantom_raster_band: PhantomData,
+        }
+    }
+}
+
+impl Drop for RasterAttributeTable<'_> {
+    fn drop(&mut self) {
+        if self.rust_owned {
+            unsafe { gdal_sys::GDALDestroyRasterAttributeTable(self.c_rat) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DriverManager;
+
+    fn land_cover() -> RasterAttributeTable<'static> {
+        let mut rat = RasterAttributeTable::new();
+        rat.create_column("value", RatFieldType::Integer, RatFieldUsage::MinMax)
+            .unwrap();
+        rat.create_column("class", RatFieldType::String, RatFieldUsage::Name)
+            .unwrap();
+        rat.create_column("area", RatFieldType::Real, RatFieldUsage::Generic)
+            .unwrap();
+        rat.set_row_count(3);
+        rat.write_column_as_int(0, 0, &[10, 20, 30]).unwrap();
+        rat.write_column_as_string(1, 0, &["forest", "water", "urban"])
+            .unwrap();
+        rat.write_column_as_double(2, 0, &[1.5, 2.5, 3.5]).unwrap();
+        rat
+    }
+
+    #[test]
+    fn test_rat_columns() {
+        let rat = land_cover();
+        assert_eq!(rat.column_count(), 3);
+        assert_eq!(rat.row_count(), 3);
+        assert_eq!(rat.column_name(1).as_deref(), Some("class"));
+        assert_eq!(rat.column_type(2), Some(RatFieldType::Real));
+        assert_eq!(rat.column_usage(0), Some(RatFieldUsage::MinMax));
+        assert_eq!(rat.column_of_usage(RatFieldUsage::Name), Some(1));
+        assert_eq!(rat.column_of_usage(RatFieldUsage::Red), None);
+        assert_eq!(rat.column_index("area"), Some(2));
+        assert_eq!(rat.column_name(3), None);
+    }
+
+    #[test]
+    fn test_rat_values() {
+        let mut rat = land_cover();
+        assert_eq!(rat.value_as_string(1, 1).unwrap(), "water");
+        assert_eq!(rat.value_as_int(2, 0).unwrap(), 30);
+        assert_eq!(rat.value_as_double(0, 2).unwrap(), 1.5);
+        assert_eq!(rat.value_as_string(0, 0).unwrap(), "10");
+        assert!(rat.value_as_int(0, 5).is_err());
+
+        rat.set_value_as_string(2, 1, "built-up").unwrap();
+        rat.set_value_as_int(2, 0, 40).unwrap();
+        rat.set_value_as_double(2, 2, 4.0).unwrap();
+        assert_eq!(
+            rat.read_column_as_string(1, 1, 2).unwrap(),
+            ["water", "built-up"]
+        );
+        assert_eq!(rat.read_column_as_int(0, 0, 3).unwrap(), [10, 20, 40]);
+        assert_eq!(rat.read_column_as_double(2, 0, 3).unwrap(), [1.5, 2.5, 4.0]);
+        assert!(rat.read_column_as_double(2, 2, 5).is_err());
+    }
+
+    #[test]
+    fn test_rat_lookup() {
+        let rat = land_cover();
+        assert_eq!(rat.row_of_value(20.0), Some(1));
+        assert_eq!(rat.row_of_value(25.0), None);
+        assert_eq!(rat.linear_binning(), None);
+
+        let mut binned = RasterAttributeTable::new();
+        binned
+            .create_column("count", RatFieldType::Integer, RatFieldUsage::PixelCount)
+            .unwrap();
+        binned.set_row_count(4);
+        binned.set_linear_binning(100.0, 10.0).unwrap();
+        assert_eq!(binned.linear_binning(), Some((100.0, 10.0)));
+        assert_eq!(binned.row_of_value(125.0), Some(2));
+        assert_eq!(binned.row_of_value(99.0), None);
+
+        binned.set_table_type(RatTableType::Athematic).unwrap();
+        assert_eq!(binned.table_type(), Some(RatTableType::Athematic));
+    }
+
+    #[test]
+    fn test_band_default_rat() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let ds = driver.create("", 4, 4, 1).unwrap();
+        let mut band = ds.rasterband(1).unwrap();
+        assert!(band.default_rat().is_none());
+
+        let rat = land_cover();
+        band.set_default_rat(&rat).unwrap();
+        drop(rat);
+
+        let rat = band.default_rat().unwrap();
+        assert_eq!(rat.row_count(), 3);
+        assert_eq!(rat.value_as_string(2, 1).unwrap(), "urban");
+
+        let copy = rat.clone();
+        assert_eq!(copy.read_column_as_int(0, 0, 3).unwrap(), [10, 20, 30]);
+    }
+}
@@ -3,8 +3,8 @@ use crate::errors::Result;
 use crate::metadata::Metadata;
 use crate::raster::rasterband::ResampleAlg;
 use crate::raster::{
-    ByteBuffer, ColorEntry, ColorInterpretation, ColorTable, GdalDataType, RasterCreationOptions,
-    StatisticsAll, StatisticsMinMax,
+    Buffer3, ByteBuffer, ColorEntry, ColorInterpretation, ColorTable, DataCoverageFlags,
+    GdalDataType, Interleaving, RasterCreationOptions, StatisticsAll, StatisticsMinMax,
 };
 use crate::test_utils::{fixture, TempFixture};
 use crate::vsi::unlink_mem_file;
@@ -125,6 +125,346 @@ fn test_write_raster() {
     assert_eq!(right.data()[0], 20u8);
 }
 
+#[test]
+fn test_read_dataset_as() {
+    let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    let bands = (1..=3)
+        .map(|b| {
+            dataset
+                .rasterband(b)
+                .unwrap()
+                .read_as::<u8>((20, 30), (2, 3), (2, 3), None)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for interleaving in [Interleaving::Band, Interleaving::Pixel, Interleaving::Line] {
+        let buf = dataset
+            .read_as::<u8>((20, 30), (2, 3), (2, 3), &[], interleaving, None)
+            .unwrap();
+        assert_eq!(buf.shape(), (2, 3, 3));
+        assert_eq!(buf.interleaving(), interleaving);
+        for (b, band) in bands.iter().enumerate() {
+            assert_eq!(&buf.band(b), band);
+        }
+    }
+
+    let buf = dataset
+        .read_as::<u8>((20, 30), (2, 3), (2, 3), &[3, 1], Interleaving::Pixel, None)
+        .unwrap();
+    assert_eq!(
+        &buf.data()[..4],
+        [
+            bands[2][(0, 0)],
+            bands[0][(0, 0)],
+            bands[2][(0, 1)],
+            bands[0][(0, 1)]
+        ]
+    );
+}
+
+#[test]
+fn test_write_dataset() {
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let mut dataset = driver.create("", 2, 2, 3).unwrap();
+
+    let mut rgb = Buffer3::new(
+        (2, 2, 3),
+        Interleaving::Pixel,
+        vec![1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+    );
+    dataset.write((0, 0), (2, 2), &[], &mut rgb).unwrap();
+    assert!(dataset.write((0, 0), (2, 2), &[1, 2], &mut rgb).is_err());
+
+    let buf = dataset
+        .read_as::<u8>((0, 0), (2, 2), (2, 2), &[], Interleaving::Band, None)
+        .unwrap();
+    assert_eq!(buf.data(), [1, 4, 7, 10, 2, 5, 8, 11, 3, 6, 9, 12]);
+
+    let green = dataset.rasterband(2).unwrap().read_band_as::<u8>().unwrap();
+    assert_eq!(green.data(), [2, 5, 8, 11]);
+}
+
+#[test]
+fn test_data_coverage() {
+    let path = "/vsimem/test_data_coverage.tif";
+    let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+    let options = RasterCreationOptions::from_iter([
+        "TILED=YES",
+        "BLOCKXSIZE=16",
+        "BLOCKYSIZE=16",
+        "SPARSE_OK=TRUE",
+    ]);
+    {
+        let mut dataset = driver
+            .create_with_band_type_with_options::<u8, _>(path, 32, 32, 1, &options)
+            .unwrap();
+        let mut band = dataset.rasterband(1).unwrap();
+        band.write_block((0, 0), &mut ByteBuffer::new((16, 16), vec![1; 256]))
+            .unwrap();
+        dataset.flush_cache().unwrap();
+    }
+
+    {
+        let dataset = Dataset::open(path).unwrap();
+        let band = dataset.rasterband(1).unwrap();
+        assert_eq!(
+            band.data_coverage((0, 0), (16, 16)).unwrap(),
+            (DataCoverageFlags::DATA, 100.0)
+        );
+        assert_eq!(
+            band.data_coverage((16, 16), (16, 16)).unwrap(),
+            (DataCoverageFlags::EMPTY, 0.0)
+        );
+        assert_eq!(
+            band.data_coverage((0, 0), (32, 32)).unwrap(),
+            (DataCoverageFlags::DATA | DataCoverageFlags::EMPTY, 25.0)
+        );
+    }
+    driver.delete(path).unwrap();
+}
+
+#[test]
+fn test_rasterband_blocks() {
+    let dataset = Dataset::open(fixture("m_3607824_se_17_1_20160620_sub.tif")).unwrap();
+    let band = dataset.rasterband(1).unwrap();
+    let (cols, rows) = band.size();
+    let expected = band
+        .read_as::<u8>((0, 0), (cols, rows), (cols, rows), None)
+        .unwrap();
+
+    let blocks = band.blocks::<u8>();
+    let (x_blocks, y_blocks) = band.block_count();
+    assert_eq!(blocks.len(), x_blocks * y_blocks);
+    let mut data = vec![0u8; cols * rows];
+    for block in blocks {
+        let (_, window, buffer) = block.unwrap();
+        assert_eq!(buffer.shape(), window.size);
+        for row in 0..window.size.1 {
+            for col in 0..window.size.0 {
+                let x = window.offset.0 as usize + col;
+                let y = window.offset.1 as usize + row;
+                data[y * cols + x] = buffer[(row, col)];
+            }
+        }
+    }
+    assert_eq!(data, expected.data());
+}
+
+#[cfg(all(feature = "rayon", any(major_ge_4, all(major_is_3, minor_ge_10))))]
+#[test]
+fn test_par_blocks() {
+    use crate::{DatasetOptions, GdalOpenFlags};
+    use rayon::iter::ParallelIterator;
+
+    let input = Dataset::open_ex(
+        fixture("tinymarble.tif"),
+        DatasetOptions {
+            open_flags: GdalOpenFlags::GDAL_OF_RASTER | GdalOpenFlags::GDAL_OF_THREAD_SAFE,
+            ..Default::default()
+        },
+    )
+    .unwrap()
+    .try_into_thread_safe(GdalOpenFlags::GDAL_OF_RASTER)
+    .unwrap();
+    let (cols, rows) = input.raster_size();
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let output = driver
+        .create_with_band_type::<u8, _>("", cols, rows, 1)
+        .unwrap();
+
+    let blocks = input.par_blocks::<u8>(1).unwrap().map(|block| {
+        let (_, window, mut buffer) = block?;
+        buffer.data_mut().iter_mut().for_each(|v| *v = 255 - *v);
+        Ok((window, buffer))
+    });
+    output
+        .rasterband(1)
+        .unwrap()
+        .par_write_blocks(blocks)
+        .unwrap();
+
+    let expected = input
+        .rasterband(1)
+        .unwrap()
+        .read_as::<u8>((0, 0), (cols, rows), (cols, rows), None)
+        .unwrap();
+    let actual = output
+        .rasterband(1)
+        .unwrap()
+        .read_as::<u8>((0, 0), (cols, rows), (cols, rows), None)
+        .unwrap();
+    assert!(expected
+        .data()
+        .iter()
+        .zip(actual.data())
+        .all(|(&e, &a)| a == 255 - e));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_write_blocks() {
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+    let input = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    let blocks = input
+        .rasterband(1)
+        .unwrap()
+        .blocks::<u8>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    let (cols, rows) = input.raster_size();
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let output = driver
+        .create_with_band_type::<u8, _>("", cols, rows, 1)
+        .unwrap();
+
+    let blocks = blocks.into_par_iter().map(|(_, window, mut buffer)| {
+        buffer.data_mut().iter_mut().for_each(|v| *v = 255 - *v);
+        Ok((window, buffer))
+    });
+    output
+        .rasterband(1)
+        .unwrap()
+        .par_write_blocks(blocks)
+        .unwrap();
+
+    let expected = input
+        .rasterband(1)
+        .unwrap()
+        .read_as::<u8>((0, 0), (cols, rows), (cols, rows), None)
+        .unwrap();
+    let actual = output
+        .rasterband(1)
+        .unwrap()
+        .read_as::<u8>((0, 0), (cols, rows), (cols, rows), None)
+        .unwrap();
+    assert!(expected
+        .data()
+        .iter()
+        .zip(actual.data())
+        .all(|(&e, &a)| a == 255 - e));
+}
+
+/// Sum of the valid pixels of the 3x3 neighbourhood of each pixel, computed in chunks.
+fn focal_sum(parallel: bool) -> (Vec<f32>, Vec<f32>) {
+    use crate::raster::{process_chunks, Buffer, ChunkOptions, Neighbourhood};
+
+    let (cols, rows) = (7, 5);
+    let values = (0..cols * rows).map(|v| v as f32).collect::<Vec<_>>();
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver
+        .create_with_band_type::<f32, _>("", cols, rows, 2)
+        .unwrap();
+    let mut input = dataset.rasterband(1).unwrap();
+    input.set_no_data_value(Some(8.0)).unwrap();
+    input
+        .write(
+            (0, 0),
+            (cols, rows),
+            &mut Buffer::new((cols, rows), values.clone()),
+        )
+        .unwrap();
+
+    let options = ChunkOptions {
+        chunk_size: Some((3, 2)),
+        halo: 1,
+        ..Default::default()
+    };
+    let sum = |chunk: &Neighbourhood<f32>| {
+        let (cols, rows) = chunk.window().size;
+        let mut data = Vec::with_capacity(cols * rows);
+        for y in 0..rows as isize {
+            for x in 0..cols as isize {
+                let sum = (-1..=1)
+                    .flat_map(|dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+                    .filter_map(|pixel| chunk.value(0, pixel))
+                    .sum::<f32>();
+                data.push(sum);
+            }
+        }
+        Ok(vec![Buffer::new((cols, rows), data)])
+    };
+    let inputs = [dataset.rasterband(1).unwrap()];
+    let mut outputs = [dataset.rasterband(2).unwrap()];
+    if parallel {
+        #[cfg(feature = "rayon")]
+        crate::raster::par_process_chunks(&inputs, &mut outputs, &options, sum).unwrap();
+        #[cfg(not(feature = "rayon"))]
+        unreachable!("parallel processing requires the `rayon` feature");
+    } else {
+        process_chunks(&inputs, &mut outputs, &options, sum).unwrap();
+    }
+
+    let mut expected = Vec::with_capacity(cols * rows);
+    for y in 0..rows as isize {
+        for x in 0..cols as isize {
+            let mut sum = 0.0;
+            for (px, py) in (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (x + dx, y + dy))) {
+                if (0..cols as isize).contains(&px) && (0..rows as isize).contains(&py) {
+                    let value = values[py as usize * cols + px as usize];
+                    if value != 8.0 {
+                        sum += value;
+                    }
+                }
+            }
+            expected.push(sum);
+        }
+    }
+    let actual = outputs[0]
+        .read_as::<f32>((0, 0), (cols, rows), (cols, rows), None)
+        .unwrap();
+    (expected, actual.data().to_vec())
+}
+
+#[test]
+fn test_process_chunks() {
+    let (expected, actual) = focal_sum(false);
+    assert_eq!(actual, expected);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_process_chunks() {
+    let (expected, actual) = focal_sum(true);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_process_chunks_unrepresentable_no_data() {
+    use crate::raster::{process_chunks, Buffer, ChunkOptions, Neighbourhood};
+
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create_with_band_type::<i16, _>("", 2, 2, 2).unwrap();
+    let mut input = dataset.rasterband(1).unwrap();
+    input.set_no_data_value(Some(-32768.0)).unwrap();
+    input
+        .write(
+            (0, 0),
+            (2, 2),
+            &mut Buffer::new((2, 2), vec![0i16, 1, 2, 3]),
+        )
+        .unwrap();
+
+    // -32768 would be clamped to 0 as `u8`, so it must not be used as the nodata value
+    let inputs = [dataset.rasterband(1).unwrap()];
+    let mut outputs = [dataset.rasterband(2).unwrap()];
+    let valid = |chunk: &Neighbourhood<u8>| {
+        assert_eq!(chunk.no_data_value(0), None);
+        let data = [(0, 0), (1, 0), (0, 1), (1, 1)]
+            .into_iter()
+            .map(|pixel| chunk.value(0, pixel).unwrap_or(255))
+            .collect();
+        Ok(vec![Buffer::new((2, 2), data)])
+    };
+    process_chunks(&inputs, &mut outputs, &ChunkOptions::default(), valid).unwrap();
+
+    let actual = outputs[0]
+        .read_as::<u8>((0, 0), (2, 2), (2, 2), None)
+        .unwrap();
+    assert_eq!(actual.data(), [0, 1, 2, 3]);
+}
+
 #[test]
 fn test_rename_remove_raster() {
     let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
@@ -681,6 +1021,36 @@ fn test_rasterband_unit() {
     assert_eq!(rasterband.unit(), "m".to_string());
 }
 
+#[test]
+fn test_rasterband_category_names_and_unit() {
+    let path = "/vsimem/test_rasterband_category_names_and_unit.tif";
+    let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+    {
+        let dataset = driver.create(path, 4, 4, 1).unwrap();
+        let mut rasterband = dataset.rasterband(1).unwrap();
+        assert!(rasterband.category_names().is_empty());
+        assert!(!rasterband.has_arbitrary_overviews());
+
+        rasterband
+            .set_category_names(&["nodata", "forest", "", "water"])
+            .unwrap();
+        rasterband.set_unit("class").unwrap();
+        rasterband.set_description("land cover").unwrap();
+    }
+
+    {
+        let dataset = Dataset::open(path).unwrap();
+        let rasterband = dataset.rasterband(1).unwrap();
+        assert_eq!(
+            rasterband.category_names(),
+            ["nodata", "forest", "", "water"]
+        );
+        assert_eq!(rasterband.unit(), "class");
+        assert_eq!(rasterband.description().unwrap(), "land cover");
+    }
+    driver.delete(path).unwrap();
+}
+
 #[test]
 fn test_color_table() {
     use crate::raster::rasterband::{ColorEntry, PaletteInterpretation};
@@ -806,6 +1176,77 @@ fn test_raster_stats() {
     );
 }
 
+#[test]
+fn test_raster_compute_statistics() {
+    let fixture = TempFixture::fixture("tinymarble.tif");
+    let expected = StatisticsAll {
+        min: 0.0,
+        max: 255.0,
+        mean: 68.4716,
+        std_dev: 83.68444773934999,
+    };
+
+    {
+        let mut dataset = Dataset::open(&fixture).unwrap();
+        let mut rb = dataset.rasterband(1).unwrap();
+
+        let mut fractions = Vec::new();
+        let stats = rb
+            .compute_statistics(
+                false,
+                Some(&mut |complete| {
+                    fractions.push(complete);
+                    true
+                }),
+            )
+            .unwrap();
+        assert_eq!(StatisticsAll::from(stats), expected);
+        assert_eq!(stats.valid_percent, Some(100.0));
+        assert_eq!(fractions.last(), Some(&1.0));
+        assert_eq!(rb.get_statistics(false, false).unwrap(), Some(expected));
+
+        assert!(rb.compute_statistics(false, Some(&mut |_| false)).is_err());
+
+        rb.clear_statistics().unwrap();
+        assert!(rb.get_statistics(false, false).unwrap().is_none());
+
+        let custom = StatisticsAll {
+            min: 1.0,
+            max: 2.0,
+            mean: 1.5,
+            std_dev: 0.5,
+        };
+        rb.set_statistics(&custom).unwrap();
+        assert_eq!(rb.get_statistics(false, false).unwrap(), Some(custom));
+
+        let mut fractions = Vec::new();
+        let stats = dataset
+            .compute_statistics(
+                false,
+                Some(&mut |complete| {
+                    fractions.push(complete);
+                    true
+                }),
+            )
+            .unwrap();
+        assert_eq!(stats.len(), 3);
+        assert_eq!(StatisticsAll::from(stats[0]), expected);
+        assert!(fractions.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(fractions.last(), Some(&1.0));
+
+        // Persist the statistics to the .aux.xml file
+        dataset.flush_cache().unwrap();
+    }
+
+    let mut dataset = Dataset::open(&fixture).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    assert_eq!(rb.get_statistics(false, false).unwrap(), Some(expected));
+
+    dataset.clear_statistics();
+    let rb = dataset.rasterband(1).unwrap();
+    assert!(rb.get_statistics(false, false).unwrap().is_none());
+}
+
 #[test]
 fn test_raster_get_histogram() {
     let fixture = TempFixture::fixture("tinymarble.tif");
@@ -0,0 +1,370 @@
+use std::ffi::{c_int, c_void};
+use std::ptr::null_mut;
+
+use gdal_sys::CPLErr;
+
+use crate::cpl::{CslStringList, CslStringListEntry};
+use crate::dataset::Dataset;
+use crate::errors::*;
+use crate::spatial_ref::SpatialRef;
+use crate::utils::{_last_cpl_err, _last_null_pointer_err};
+use crate::GeoTransform;
+
+/// The georeferencing used by a [`Transformer`] to map pixels to coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformerMethod {
+    /// The affine geo-transform of the dataset.
+    GeoTransform,
+    /// A polynomial fitted to the GCPs of the dataset, of order 1 to 3, or selected from the
+    /// number of GCPs if `None`.
+    GcpPolynomial(Option<u8>),
+    /// A thin plate spline through the GCPs of the dataset, going exactly through each GCP.
+    GcpTps,
+    /// The rational polynomial coefficients from the `RPC` metadata domain of the dataset.
+    Rpc,
+}
+
+impl TransformerMethod {
+    fn to_gdal_option(self) -> &'static str {
+        match self {
+            TransformerMethod::GeoTransform => "GEOTRANSFORM",
+            TransformerMethod::GcpPolynomial(_) => "GCP_POLYNOMIAL",
+            TransformerMethod::GcpTps => "GCP_TPS",
+            TransformerMethod::Rpc => "RPC",
+        }
+    }
+}
+
+/// Options for [`Transformer::new`] and [`Dataset::auto_warped_vrt`](crate::Dataset::auto_warped_vrt).
+///
+/// See: [`GDALCreateGenImgProjTransformer2`](https://gdal.org/api/gdal_alg.html#_CPPv432GDALCreateGenImgProjTransformer212GDALDatasetH12GDALDatasetHPPc)
+/// for all the options.
+#[derive(Debug, Clone, Default)]
+pub struct TransformerOptions {
+    options: CslStringList,
+}
+
+impl TransformerOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the georeferencing method. Defaults to the geo-transform, then the GCPs, then the
+    /// RPCs, whichever the dataset has first.
+    pub fn with_method(&mut self, method: TransformerMethod) -> Result<&mut Self> {
+        self.options
+            .set_name_value("METHOD", method.to_gdal_option())?;
+        if let TransformerMethod::GcpPolynomial(Some(order)) = method {
+            self.options
+                .set_name_value("MAX_GCP_ORDER", &order.to_string())?;
+        } else {
+            // don't keep the order of a previous method
+            self.options = self
+                .options
+                .iter()
+                .filter(|entry| {
+                    !matches!(entry, CslStringListEntry::Pair { name, .. }
+                        if name.eq_ignore_ascii_case("MAX_GCP_ORDER"))
+                })
+                .collect();
+        }
+        Ok(self)
+    }
+
+    /// Set the spatial reference of the source, overriding the one of the dataset.
+    pub fn with_src_spatial_ref(&mut self, spatial_ref: &SpatialRef) -> Result<&mut Self> {
+        self.options
+            .set_name_value("SRC_SRS", &spatial_ref.to_wkt()?)?;
+        Ok(self)
+    }
+
+    /// Set the spatial reference of the transformed coordinates, instead of the one of the
+    /// source.
+    pub fn with_dst_spatial_ref(&mut self, spatial_ref: &SpatialRef) -> Result<&mut Self> {
+        self.options
+            .set_name_value("DST_SRS", &spatial_ref.to_wkt()?)?;
+        Ok(self)
+    }
+
+    /// Set any other option of [`GDALCreateGenImgProjTransformer2`](https://gdal.org/api/gdal_alg.html#_CPPv432GDALCreateGenImgProjTransformer212GDALDatasetH12GDALDatasetHPPc).
+    pub fn with_option(&mut self, name: &str, value: &str) -> Result<&mut Self> {
+        self.options.set_name_value(name, value)?;
+        Ok(self)
+    }
+
+    pub(crate) fn as_ptr(&self) -> gdal_sys::CSLConstList {
+        self.options.as_ptr()
+    }
+}
+
+/// Maps the pixels of a dataset to georeferenced coordinates, or to the pixels of another
+/// dataset, using its geo-transform, GCPs or RPCs.
+///
+/// Unlike [`GeoTransformEx::apply`](crate::GeoTransformEx::apply), this also works for
+/// datasets that are only georeferenced by GCPs or RPCs.
+///
+/// See: [`GDALCreateGenImgProjTransformer2`](https://gdal.org/api/gdal_alg.html#_CPPv432GDALCreateGenImgProjTransformer212GDALDatasetH12GDALDatasetHPPc)
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::{Transformer, TransformerMethod, TransformerOptions};
+/// use gdal::Dataset;
+///
+/// let dataset = Dataset::open("fixtures/gcp.tif")?;
+/// let mut options = TransformerOptions::new();
+/// options.with_method(TransformerMethod::GcpPolynomial(Some(2)))?;
+/// let transformer = Transformer::new(&dataset, None, &options)?;
+///
+/// // pixel and line of the corners
+/// let mut x = [0.0, 10.0];
+/// let mut y = [0.0, 6.0];
+/// transformer.transform_coords(&mut x, &mut y, &mut [])?;
+/// println!("longitudes: {x:?}, latitudes: {y:?}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Transformer {
+    c_transformer: *mut c_void,
+}
+
+impl Transformer {
+    /// Create a transformer from the pixels of `src` to the pixels of `dst`, or to
+    /// georeferenced coordinates if `dst` is `None`.
+    pub fn new(src: &Dataset, dst: Option<&Dataset>, options: &TransformerOptions) -> Result<Self> {
+        let c_transformer = unsafe {
+            gdal_sys::GDALCreateGenImgProjTransformer2(
+                src.c_dataset(),
+                dst.map_or(null_mut(), |dst| dst.c_dataset()),
+                options.as_ptr(),
+            )
+        };
+        if c_transformer.is_null() {
+            return Err(_last_null_pointer_err("GDALCreateGenImgProjTransformer2"));
+        }
+        Ok(Self { c_transformer })
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_transformer(&self) -> *mut c_void {
+        self.c_transformer
+    }
+
+    /// Set the geo-transform of the destination, for transformers created without a
+    /// destination dataset, so that they map to its pixels instead of to georeferenced
+    /// coordinates.
+    pub fn set_dst_geo_transform(&mut self, geo_transform: &GeoTransform) {
+        unsafe {
+            gdal_sys::GDALSetGenImgProjTransformerDstGeoTransform(
+                self.c_transformer,
+                geo_transform.as_ptr(),
+            )
+        };
+    }
+
+    /// Transform coordinates from the source to the destination in place.
+    ///
+    /// # Arguments
+    /// * `x` - slice of x coordinates, i.e. pixels for datasets
+    /// * `y` - slice of y coordinates, i.e. lines for datasets (must match x in length)
+    /// * `z` - slice of z coordinates, or an empty slice to ignore
+    ///
+    /// # Errors
+    /// If any of the coordinates can't be transformed.
+    pub fn transform_coords(&self, x: &mut [f64], y: &mut [f64], z: &mut [f64]) -> Result<()> {
+        self.transform(false, x, y, z)
+    }
+
+    /// Transform coordinates from the destination back to the source in place.
+    ///
+    /// See: [`Transformer::transform_coords`]
+    pub fn inverse_transform_coords(
+        &self,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+    ) -> Result<()> {
+        self.transform(true, x, y, z)
+    }
+
+    fn transform(
+        &self,
+        dst_to_src: bool,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+    ) -> Result<()> {
+        let nb_coords = x.len();
+        assert_eq!(
+            nb_coords,
+            y.len(),
+            "transform coordinate slices have different lengths: {} != {}",
+            nb_coords,
+            y.len()
+        );
+        assert!(
+            z.is_empty() || z.len() == nb_coords,
+            "transform coordinate slices have different lengths: {} != {}",
+            nb_coords,
+            z.len()
+        );
+        // some transformers require z
+        let mut zeros = Vec::new();
+        let z = if z.is_empty() {
+            zeros.resize(nb_coords, 0.0);
+            &mut zeros[..]
+        } else {
+            z
+        };
+
+        let mut success = vec![0 as c_int; nb_coords];
+        let rv = unsafe {
+            gdal_sys::GDALGenImgProjTransform(
+                self.c_transformer,
+                dst_to_src as c_int,
+                nb_coords.try_into()?,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                z.as_mut_ptr(),
+                success.as_mut_ptr(),
+            )
+        };
+        if rv == 0 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        if let Some(index) = success.iter().position(|&s| s == 0) {
+            return Err(GdalError::BadArgument(format!(
+                "failed to transform coordinates at index {index}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Suggest the size and geo-transform of a dataset that would contain all of `src` once
+    /// transformed, at about the same resolution, as used by `gdalwarp`.
+    ///
+    /// The transformer must map the pixels of `src` to georeferenced coordinates.
+    ///
+    /// See: [`GDALSuggestedWarpOutput`](https://gdal.org/api/gdal_alg.html#_CPPv423GDALSuggestedWarpOutput12GDALDatasetH19GDALTransformerFuncPvPdPiPi)
+    pub fn suggested_warp_output(&self, src: &Dataset) -> Result<((usize, usize), GeoTransform)> {
+        let mut geo_transform = GeoTransform::default();
+        let (mut cols, mut rows) = (0, 0);
+        let rv = unsafe {
+            gdal_sys::GDALSuggestedWarpOutput(
+                src.c_dataset(),
+                Some(gdal_sys::GDALGenImgProjTransform),
+                self.c_transformer,
+                geo_transform.as_mut_ptr(),
+                &mut cols,
+                &mut rows,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(((cols.try_into()?, rows.try_into()?), geo_transform))
+    }
+
+    /// Give up ownership of the wrapped C pointer, e.g. to a warped VRT.
+    pub(crate) fn into_c_transformer(self) -> *mut c_void {
+        let c_transformer = self.c_transformer;
+        std::mem::forget(self);
+        c_transformer
+    }
+}
+
+impl Drop for Transformer {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALDestroyGenImgProjTransformer(self.c_transformer) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixture;
+    use crate::GeoTransformEx;
+
+    #[test]
+    fn test_geo_transform_transformer() {
+        let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let transformer = Transformer::new(&dataset, None, &TransformerOptions::new()).unwrap();
+        let gt = dataset.geo_transform().unwrap();
+
+        let (mut x, mut y) = ([0.0, 10.5], [0.0, 20.25]);
+        transformer
+            .transform_coords(&mut x, &mut y, &mut [])
+            .unwrap();
+        for (i, (pixel, line)) in [(0.0, 0.0), (10.5, 20.25)].into_iter().enumerate() {
+            let (gx, gy) = gt.apply(pixel, line);
+            assert!((x[i] - gx).abs() < 1e-9);
+            assert!((y[i] - gy).abs() < 1e-9);
+        }
+
+        transformer
+            .inverse_transform_coords(&mut x, &mut y, &mut [])
+            .unwrap();
+        assert!((x[1] - 10.5).abs() < 1e-6);
+        assert!((y[1] - 20.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_gcp_transformer() {
+        let dataset = Dataset::open(fixture("gcp.tif")).unwrap();
+        let gcps = dataset.gcps();
+        let (first, last) = (&gcps[0], &gcps[gcps.len() - 1]);
+
+        // a thin plate spline goes through the GCPs
+        let mut options = TransformerOptions::new();
+        options.with_method(TransformerMethod::GcpTps).unwrap();
+        let transformer = Transformer::new(&dataset, None, &options).unwrap();
+        let mut x = [first.pixel(), last.pixel()];
+        let mut y = [first.line(), last.line()];
+        transformer
+            .transform_coords(&mut x, &mut y, &mut [])
+            .unwrap();
+        assert!((x[0] - first.x()).abs() < 1e-6);
+        assert!((y[0] - first.y()).abs() < 1e-6);
+        assert!((x[1] - last.x()).abs() < 1e-6);
+        assert!((y[1] - last.y()).abs() < 1e-6);
+        // and so does its inverse
+        transformer
+            .inverse_transform_coords(&mut x, &mut y, &mut [])
+            .unwrap();
+        assert!((x[0] - first.pixel()).abs() < 1e-6);
+        assert!((y[0] - first.line()).abs() < 1e-6);
+
+        // a polynomial is only close to them
+        let mut options = TransformerOptions::new();
+        options
+            .with_method(TransformerMethod::GcpPolynomial(Some(1)))
+            .unwrap();
+        let transformer = Transformer::new(&dataset, None, &options).unwrap();
+        let mut x = [first.pixel()];
+        let mut y = [first.line()];
+        transformer
+            .transform_coords(&mut x, &mut y, &mut [])
+            .unwrap();
+        assert!((x[0] - first.x()).abs() < 0.1);
+        assert!((y[0] - first.y()).abs() < 0.1);
+
+        // the order is only kept for the polynomial it was given with
+        options
+            .with_method(TransformerMethod::GcpPolynomial(None))
+            .unwrap();
+        assert_eq!(options.options.fetch_name_value("MAX_GCP_ORDER"), None);
+        assert!(Transformer::new(&dataset, None, &options).is_ok());
+
+        // a geo-transform is missing
+        let mut options = TransformerOptions::new();
+        options
+            .with_method(TransformerMethod::GeoTransform)
+            .unwrap();
+        assert!(Transformer::new(&dataset, None, &options).is_err());
+    }
+}
@@ -0,0 +1,917 @@
+use std::collections::HashMap;
+use std::ffi::{c_int, c_void, CString};
+use std::fmt::Write;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use gdal_sys::{CPLErr, CSLConstList, GDALDataType};
+
+use crate::errors::*;
+use crate::raster::{Buffer, GdalDataType, Window};
+use crate::spatial_ref::SpatialRef;
+use crate::utils::{_last_cpl_err, _string};
+use crate::{Dataset, DatasetOptions, GdalOpenFlags, GeoTransform};
+
+/// A pixel function computing the values of a derived VRT band from its sources.
+///
+/// Besides the built-in functions available through the constructors below, any function
+/// known to GDAL can be used with [`PixelFunction::new`], including the ones registered with
+/// [`register_pixel_function`].
+///
+/// See: [Built-in pixel functions](https://gdal.org/drivers/raster/vrt.html#built-in-pixel-functions)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelFunction {
+    name: String,
+    arguments: Vec<(String, String)>,
+}
+
+impl PixelFunction {
+    /// Use the pixel function registered under `name`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            arguments: Vec::new(),
+        }
+    }
+
+    /// Sum of the sources, plus an optional constant `k` argument.
+    pub fn sum() -> Self {
+        Self::new("sum")
+    }
+
+    /// Product of the sources, times an optional constant `k` argument.
+    pub fn mul() -> Self {
+        Self::new("mul")
+    }
+
+    /// Applies the offset and scale of the source band, i.e. computes its unscaled values.
+    pub fn scale() -> Self {
+        Self::new("scale")
+    }
+
+    /// Evaluates an `expression` over the sources, named `B1`, `B2`... by default.
+    ///
+    /// This requires GDAL to be built with muparser or ExprTk, and the `dialect` argument selects
+    /// between them.
+    pub fn expression(expression: &str) -> Self {
+        let mut function = Self::new("expression");
+        function.with_argument("expression", expression);
+        function
+    }
+
+    /// Linear interpolation at time `t` between the sources, sampled every `dt` from `t0`.
+    pub fn interpolate_linear(t0: f64, dt: f64, t: f64) -> Self {
+        Self::interpolate("interpolate_linear", t0, dt, t)
+    }
+
+    /// Exponential interpolation at time `t` between the sources, sampled every `dt` from `t0`.
+    pub fn interpolate_exp(t0: f64, dt: f64, t: f64) -> Self {
+        Self::interpolate("interpolate_exp", t0, dt, t)
+    }
+
+    fn interpolate(name: &str, t0: f64, dt: f64, t: f64) -> Self {
+        let mut function = Self::new(name);
+        function
+            .with_argument("t0", t0)
+            .with_argument("dt", dt)
+            .with_argument("t", t);
+        function
+    }
+
+    /// Set an argument of the pixel function, written to `<PixelFunctionArguments>`.
+    pub fn with_argument(&mut self, name: &str, value: impl ToString) -> &mut Self {
+        let value = value.to_string();
+        match self.arguments.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value,
+            None => self.arguments.push((name.to_string(), value)),
+        }
+        self
+    }
+
+    /// The name of the pixel function.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The arguments of the pixel function, as `(name, value)` pairs.
+    pub fn arguments(&self) -> &[(String, String)] {
+        &self.arguments
+    }
+}
+
+type PixelFn = dyn Fn(&[Buffer<f64>], &mut Buffer<f64>) -> Result<()> + Send + Sync;
+
+static PIXEL_FUNCTIONS: LazyLock<RwLock<HashMap<String, Arc<PixelFn>>>> =
+    LazyLock::new(Default::default);
+
+/// The constant argument used to find the Rust function called by [`pixel_function_trampoline`].
+const FUNCTION_ARGUMENT: &str = "GDAL_RS_PIXEL_FUNCTION";
+
+/// Register a Rust closure as a VRT pixel function, usable by derived bands with
+/// [`PixelFunction::new`].
+///
+/// The closure receives the data of the sources, converted to `f64`, and fills the output
+/// buffer, which has the same shape; GDAL converts the output to the band type. Returning an
+/// error, or panicking, fails the read of the derived band.
+///
+/// Registering a function again under the same name replaces it.
+///
+/// See: [`GDALAddDerivedBandPixelFuncWithArgs`](https://gdal.org/api/raster_c_api.html#_CPPv435GDALAddDerivedBandPixelFuncWithArgsPKc28GDALDerivedPixelFuncWithArgsPKc)
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::register_pixel_function;
+///
+/// register_pixel_function("ndvi", |sources, output| {
+///     let (red, nir) = (sources[0].data(), sources[1].data());
+///     for (i, value) in output.data_mut().iter_mut().enumerate() {
+///         *value = (nir[i] - red[i]) / (nir[i] + red[i]);
+///     }
+///     Ok(())
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_pixel_function<F>(name: &str, function: F) -> Result<()>
+where
+    F: Fn(&[Buffer<f64>], &mut Buffer<f64>) -> Result<()> + Send + Sync + 'static,
+{
+    // GDAL doesn't pass any user data to pixel functions, so we make it pass the name of the
+    // function as a constant argument, and look it up in `PIXEL_FUNCTIONS`.
+    let metadata = format!(
+        "<PixelFunctionArgumentsList><Argument name=\"{FUNCTION_ARGUMENT}\" type=\"constant\" value=\"{}\"/></PixelFunctionArgumentsList>",
+        escape(name)
+    );
+    let c_name = CString::new(name)?;
+    let c_metadata = CString::new(metadata)?;
+
+    PIXEL_FUNCTIONS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(name.to_string(), Arc::new(function));
+    let rv = unsafe {
+        gdal_sys::GDALAddDerivedBandPixelFuncWithArgs(
+            c_name.as_ptr(),
+            Some(pixel_function_trampoline),
+            c_metadata.as_ptr(),
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(_last_cpl_err(rv));
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn pixel_function_trampoline(
+    sources: *mut *mut c_void,
+    source_count: c_int,
+    data: *mut c_void,
+    x_size: c_int,
+    y_size: c_int,
+    source_type: GDALDataType::Type,
+    buffer_type: GDALDataType::Type,
+    pixel_space: c_int,
+    line_space: c_int,
+    arguments: CSLConstList,
+) -> CPLErr::Type {
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let c_key = CString::new(FUNCTION_ARGUMENT).unwrap();
+        let name =
+            _string(gdal_sys::CSLFetchNameValue(arguments, c_key.as_ptr())).unwrap_or_default();
+        let function = PIXEL_FUNCTIONS
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&name)
+            .cloned()
+            .ok_or_else(|| GdalError::BadArgument(format!("unknown pixel function {name}")))?;
+
+        let shape = (x_size as usize, y_size as usize);
+        let len = shape.0 * shape.1;
+        let source_pixel_space = gdal_sys::GDALGetDataTypeSizeBytes(source_type);
+        let inputs = (0..source_count as usize)
+            .map(|i| {
+                let mut values = vec![0.0; len];
+                gdal_sys::GDALCopyWords(
+                    *sources.add(i),
+                    source_type,
+                    source_pixel_space,
+                    values.as_mut_ptr() as *mut c_void,
+                    GDALDataType::GDT_Float64,
+                    size_of::<f64>() as c_int,
+                    len as c_int,
+                );
+                Buffer::new(shape, values)
+            })
+            .collect::<Vec<_>>();
+        let mut output = Buffer::new(shape, vec![0.0; len]);
+        function(&inputs, &mut output)?;
+
+        for (row, line) in output.data().chunks_exact(shape.0.max(1)).enumerate() {
+            gdal_sys::GDALCopyWords(
+                line.as_ptr() as *const c_void,
+                GDALDataType::GDT_Float64,
+                size_of::<f64>() as c_int,
+                (data as *mut u8).add(row * line_space as usize) as *mut c_void,
+                buffer_type,
+                pixel_space,
+                x_size,
+            );
+        }
+        Ok::<_, GdalError>(())
+    }));
+
+    let message = match result {
+        Ok(Ok(())) => return CPLErr::CE_None,
+        Ok(Err(e)) => e.to_string(),
+        Err(_) => "pixel function panicked".to_string(),
+    };
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    // 1 is `CPLE_AppDefined`
+    gdal_sys::CPLError(CPLErr::CE_Failure, 1, c"%s".as_ptr(), message.as_ptr());
+    CPLErr::CE_Failure
+}
+
+/// A source of a [`VrtBand`], reading a band of another dataset.
+///
+/// Sources are written as a `SimpleSource`, unless one of [`VrtSource::with_scale`],
+/// [`VrtSource::with_lut`] or [`VrtSource::with_no_data_value`] is used, which requires a
+/// `ComplexSource`.
+///
+/// See: [VRT sources](https://gdal.org/drivers/raster/vrt.html#vrt-descriptions-for-raster-files)
+#[derive(Debug, Clone, PartialEq)]
+pub struct VrtSource {
+    path: String,
+    band: usize,
+    src_window: Option<Window>,
+    dst_window: Option<Window>,
+    scale: Option<(f64, f64)>,
+    lut: Vec<(f64, f64)>,
+    no_data_value: Option<f64>,
+}
+
+impl VrtSource {
+    /// Read the band `band` (_1-based_) of the dataset at `path`.
+    ///
+    /// The dataset is opened by the VRT driver, so it must be a file, possibly in `/vsimem`,
+    /// rather than e.g. a `MEM` dataset.
+    pub fn new<P: AsRef<Path>>(path: P, band: usize) -> Self {
+        Self {
+            path: path.as_ref().to_string_lossy().into_owned(),
+            band,
+            src_window: None,
+            dst_window: None,
+            scale: None,
+            lut: Vec::new(),
+            no_data_value: None,
+        }
+    }
+
+    /// Set the region of the source band to read. Defaults to the whole band.
+    pub fn with_src_window(&mut self, window: Window) -> &mut Self {
+        self.src_window = Some(window);
+        self
+    }
+
+    /// Set the region of the VRT band the source is written to, resampling it if its size
+    /// differs from the source window. Defaults to the whole band.
+    pub fn with_dst_window(&mut self, window: Window) -> &mut Self {
+        self.dst_window = Some(window);
+        self
+    }
+
+    /// Apply a linear transformation to the source values, computing
+    /// `value * ratio + offset`.
+    pub fn with_scale(&mut self, offset: f64, ratio: f64) -> &mut Self {
+        self.scale = Some((offset, ratio));
+        self
+    }
+
+    /// Map the source values through a lookup table of `(input, output)` pairs, sorted by
+    /// input, interpolating linearly between them.
+    pub fn with_lut(&mut self, lut: &[(f64, f64)]) -> &mut Self {
+        self.lut = lut.to_vec();
+        self
+    }
+
+    /// Set the nodata value of the source, whose pixels are not written to the VRT band.
+    pub fn with_no_data_value(&mut self, no_data_value: f64) -> &mut Self {
+        self.no_data_value = Some(no_data_value);
+        self
+    }
+
+    fn is_complex(&self) -> bool {
+        self.scale.is_some() || !self.lut.is_empty() || self.no_data_value.is_some()
+    }
+
+    fn write_xml(&self, xml: &mut String, indent: &str) {
+        let tag = if self.is_complex() {
+            "ComplexSource"
+        } else {
+            "SimpleSource"
+        };
+        let _ = writeln!(xml, "{indent}<{tag}>");
+        let _ = writeln!(
+            xml,
+            "{indent}  <SourceFilename relativeToVRT=\"0\">{}</SourceFilename>",
+            escape(&self.path)
+        );
+        let _ = writeln!(xml, "{indent}  <SourceBand>{}</SourceBand>", self.band);
+        for (tag, window) in [("SrcRect", self.src_window), ("DstRect", self.dst_window)] {
+            if let Some(window) = window {
+                let _ = writeln!(
+                    xml,
+                    "{indent}  <{tag} xOff=\"{}\" yOff=\"{}\" xSize=\"{}\" ySize=\"{}\"/>",
+                    window.offset.0, window.offset.1, window.size.0, window.size.1
+                );
+            }
+        }
+        if let Some((offset, ratio)) = self.scale {
+            let _ = writeln!(xml, "{indent}  <ScaleOffset>{offset}</ScaleOffset>");
+            let _ = writeln!(xml, "{indent}  <ScaleRatio>{ratio}</ScaleRatio>");
+        }
+        if !self.lut.is_empty() {
+            let lut = self
+                .lut
+                .iter()
+                .map(|(input, output)| format!("{input}:{output}"))
+                .collect::<Vec<_>>();
+            let _ = writeln!(xml, "{indent}  <LUT>{}</LUT>", lut.join(","));
+        }
+        if let Some(no_data_value) = self.no_data_value {
+            let _ = writeln!(xml, "{indent}  <NODATA>{no_data_value}</NODATA>");
+        }
+        let _ = writeln!(xml, "{indent}</{tag}>");
+    }
+}
+
+/// Metadata items, as `(domain, key, value)`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct VrtMetadata(Vec<(String, String, String)>);
+
+impl VrtMetadata {
+    fn set(&mut self, key: &str, value: &str, domain: &str) {
+        match self.0.iter_mut().find(|(d, k, _)| d == domain && k == key) {
+            Some((_, _, v)) => *v = value.to_string(),
+            None => self
+                .0
+                .push((domain.to_string(), key.to_string(), value.to_string())),
+        }
+    }
+
+    fn write_xml(&self, xml: &mut String, indent: &str) {
+        let mut domains = Vec::new();
+        for (domain, _, _) in &self.0 {
+            if !domains.contains(&domain) {
+                domains.push(domain);
+            }
+        }
+        for domain in domains {
+            if domain.is_empty() {
+                let _ = writeln!(xml, "{indent}<Metadata>");
+            } else {
+                let _ = writeln!(xml, "{indent}<Metadata domain=\"{}\">", escape(domain));
+            }
+            for (_, key, value) in self.0.iter().filter(|(d, _, _)| d == domain) {
+                let _ = writeln!(
+                    xml,
+                    "{indent}  <MDI key=\"{}\">{}</MDI>",
+                    escape(key),
+                    escape(value)
+                );
+            }
+            let _ = writeln!(xml, "{indent}</Metadata>");
+        }
+    }
+}
+
+/// A band of a [`VrtDataset`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VrtBand {
+    data_type: GdalDataType,
+    pixel_function: Option<PixelFunction>,
+    source_transfer_type: Option<GdalDataType>,
+    no_data_value: Option<f64>,
+    metadata: VrtMetadata,
+    mask_band: Option<Box<VrtBand>>,
+    sources: Vec<VrtSource>,
+}
+
+impl VrtBand {
+    /// A band of type `data_type` that mosaics its sources.
+    pub fn new(data_type: GdalDataType) -> Self {
+        Self {
+            data_type,
+            pixel_function: None,
+            source_transfer_type: None,
+            no_data_value: None,
+            metadata: VrtMetadata::default(),
+            mask_band: None,
+            sources: Vec::new(),
+        }
+    }
+
+    /// A derived band of type `data_type` (a `VRTDerivedRasterBand`), computing its values
+    /// from its sources with `pixel_function` when read.
+    pub fn derived(data_type: GdalDataType, pixel_function: &PixelFunction) -> Self {
+        let mut band = Self::new(data_type);
+        band.pixel_function = Some(pixel_function.clone());
+        band
+    }
+
+    /// Set the type the sources of a derived band are converted to before calling the pixel
+    /// function. Defaults to the type of the band.
+    pub fn with_source_transfer_type(&mut self, data_type: GdalDataType) -> &mut Self {
+        self.source_transfer_type = Some(data_type);
+        self
+    }
+
+    /// Set the nodata value of the band.
+    pub fn with_no_data_value(&mut self, no_data_value: f64) -> &mut Self {
+        self.no_data_value = Some(no_data_value);
+        self
+    }
+
+    /// Set a metadata item of the band, in `domain`, or in the default domain if empty.
+    pub fn with_metadata_item(&mut self, key: &str, value: &str, domain: &str) -> &mut Self {
+        self.metadata.set(key, value, domain);
+        self
+    }
+
+    /// Set the mask band of this band, which should be of type [`GdalDataType::UInt8`], with
+    /// `0` for invalid and `255` for valid pixels.
+    pub fn with_mask_band(&mut self, mask_band: &VrtBand) -> &mut Self {
+        self.mask_band = Some(Box::new(mask_band.clone()));
+        self
+    }
+
+    /// Add a source to the band.
+    pub fn with_source(&mut self, source: &VrtSource) -> &mut Self {
+        self.sources.push(source.clone());
+        self
+    }
+
+    /// Write the band, with its `index` unless it is a mask band.
+    fn write_xml(&self, xml: &mut String, index: Option<usize>, indent: &str) {
+        let _ = write!(
+            xml,
+            "{indent}<VRTRasterBand dataType=\"{}\"",
+            self.data_type.name()
+        );
+        if let Some(index) = index {
+            let _ = write!(xml, " band=\"{index}\"");
+        }
+        if self.pixel_function.is_some() {
+            xml.push_str(" subClass=\"VRTDerivedRasterBand\"");
+        }
+        xml.push_str(">\n");
+        let inner = format!("{indent}  ");
+        self.metadata.write_xml(xml, &inner);
+        if let Some(no_data_value) = self.no_data_value {
+            let _ = writeln!(xml, "{inner}<NoDataValue>{no_data_value}</NoDataValue>");
+        }
+        if let Some(function) = &self.pixel_function {
+            let _ = writeln!(
+                xml,
+                "{inner}<PixelFunctionType>{}</PixelFunctionType>",
+                escape(&function.name)
+            );
+            if !function.arguments.is_empty() {
+                let _ = write!(xml, "{inner}<PixelFunctionArguments");
+                for (name, value) in &function.arguments {
+                    let _ = write!(xml, " {}=\"{}\"", escape(name), escape(value));
+                }
+                xml.push_str("/>\n");
+            }
+        }
+        if let Some(data_type) = self.source_transfer_type {
+            let _ = writeln!(
+                xml,
+                "{inner}<SourceTransferType>{}</SourceTransferType>",
+                data_type.name()
+            );
+        }
+        for source in &self.sources {
+            source.write_xml(xml, &inner);
+        }
+        if let Some(mask_band) = &self.mask_band {
+            write_mask_band(xml, mask_band, &inner);
+        }
+        let _ = writeln!(xml, "{indent}</VRTRasterBand>");
+    }
+}
+
+fn write_mask_band(xml: &mut String, mask_band: &VrtBand, indent: &str) {
+    let _ = writeln!(xml, "{indent}<MaskBand>");
+    mask_band.write_xml(xml, None, &format!("{indent}  "));
+    let _ = writeln!(xml, "{indent}</MaskBand>");
+}
+
+/// A builder for [VRT](https://gdal.org/drivers/raster/vrt.html) datasets, i.e. virtual
+/// datasets computed on the fly from other datasets.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::raster::{GdalDataType, PixelFunction, VrtBand, VrtDataset, VrtSource, Window};
+///
+/// let path = "fixtures/tinymarble.tif";
+///
+/// // the left half of the first band, upsampled to the whole dataset
+/// let mut source = VrtSource::new(path, 1);
+/// source.with_src_window(Window::new((0, 0), (50, 50)));
+/// let mut band = VrtBand::new(GdalDataType::UInt8);
+/// band.with_source(&source);
+///
+/// // the sum of the three bands
+/// let mut sum = VrtBand::derived(GdalDataType::UInt16, &PixelFunction::sum());
+/// sum.with_source(&VrtSource::new(path, 1))
+///     .with_source(&VrtSource::new(path, 2))
+///     .with_source(&VrtSource::new(path, 3));
+///
+/// let mut vrt = VrtDataset::new(100, 50);
+/// vrt.with_band(&band).with_band(&sum);
+/// let dataset = vrt.build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VrtDataset {
+    size: (usize, usize),
+    geo_transform: Option<GeoTransform>,
+    spatial_ref: Option<String>,
+    metadata: VrtMetadata,
+    mask_band: Option<VrtBand>,
+    bands: Vec<VrtBand>,
+}
+
+impl VrtDataset {
+    /// A VRT dataset of `cols` x `rows` pixels, without bands.
+    pub fn new(cols: usize, rows: usize) -> Self {
+        Self {
+            size: (cols, rows),
+            geo_transform: None,
+            spatial_ref: None,
+            metadata: VrtMetadata::default(),
+            mask_band: None,
+            bands: Vec::new(),
+        }
+    }
+
+    /// Set the geo-transform of the dataset.
+    pub fn with_geo_transform(&mut self, geo_transform: &GeoTransform) -> &mut Self {
+        self.geo_transform = Some(*geo_transform);
+        self
+    }
+
+    /// Set the spatial reference of the dataset.
+    pub fn with_spatial_ref(&mut self, spatial_ref: &SpatialRef) -> Result<&mut Self> {
+        self.spatial_ref = Some(spatial_ref.to_wkt()?);
+        Ok(self)
+    }
+
+    /// Set a metadata item of the dataset, in `domain`, or in the default domain if empty.
+    pub fn with_metadata_item(&mut self, key: &str, value: &str, domain: &str) -> &mut Self {
+        self.metadata.set(key, value, domain);
+        self
+    }
+
+    /// Set a mask band shared by all the bands of the dataset, which should be of type
+    /// [`GdalDataType::UInt8`], with `0` for invalid and `255` for valid pixels.
+    pub fn with_mask_band(&mut self, mask_band: &VrtBand) -> &mut Self {
+        self.mask_band = Some(mask_band.clone());
+        self
+    }
+
+    /// Add a band to the dataset.
+    pub fn with_band(&mut self, band: &VrtBand) -> &mut Self {
+        self.bands.push(band.clone());
+        self
+    }
+
+    /// Serialize the dataset to VRT XML, which can be saved to a `.vrt` file.
+    pub fn to_xml(&self) -> String {
+        let mut xml = format!(
+            "<VRTDataset rasterXSize=\"{}\" rasterYSize=\"{}\">\n",
+            self.size.0, self.size.1
+        );
+        if let Some(wkt) = &self.spatial_ref {
+            let _ = writeln!(xml, "  <SRS>{}</SRS>", escape(wkt));
+        }
+        if let Some(gt) = &self.geo_transform {
+            let _ = writeln!(
+                xml,
+                "  <GeoTransform>{}, {}, {}, {}, {}, {}</GeoTransform>",
+                gt[0], gt[1], gt[2], gt[3], gt[4], gt[5]
+            );
+        }
+        self.metadata.write_xml(&mut xml, "  ");
+        for (i, band) in self.bands.iter().enumerate() {
+            band.write_xml(&mut xml, Some(i + 1), "  ");
+        }
+        if let Some(mask_band) = &self.mask_band {
+            write_mask_band(&mut xml, mask_band, "  ");
+        }
+        xml.push_str("</VRTDataset>\n");
+        xml
+    }
+
+    /// Open the dataset with the VRT driver.
+    pub fn build(&self) -> Result<Dataset> {
+        Dataset::open_ex(
+            self.to_xml(),
+            DatasetOptions {
+                open_flags: GdalOpenFlags::GDAL_OF_RASTER,
+                allowed_drivers: Some(&["VRT"]),
+                ..Default::default()
+            },
+        )
+    }
+}
+
+/// Escape the XML special characters of `value`, for use in text or attribute values.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::Metadata;
+    use crate::raster::{RasterBand, RasterCreationOptions};
+    use crate::DriverManager;
+
+    /// Create a 2-band dataset in `/vsimem`, with values `i` and `2 * i + 1` in row-major order.
+    fn create_source(path: &str) {
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+        let dataset = driver
+            .create_with_band_type_with_options::<u8, _>(
+                path,
+                4,
+                3,
+                2,
+                &RasterCreationOptions::default(),
+            )
+            .unwrap();
+        for band_index in 1..=2 {
+            let data = (0..12).map(|i| i * band_index as u8 + band_index as u8 - 1);
+            dataset
+                .rasterband(band_index)
+                .unwrap()
+                .write((0, 0), (4, 3), &mut Buffer::new((4, 3), data.collect()))
+                .unwrap();
+        }
+    }
+
+    fn read_band(dataset: &Dataset) -> Vec<f64> {
+        let band = dataset.rasterband(1).unwrap();
+        band.read_as::<f64>((0, 0), (4, 3), (4, 3), None)
+            .unwrap()
+            .data()
+            .to_vec()
+    }
+
+    #[test]
+    fn test_vrt_xml() {
+        let mut function = PixelFunction::mul();
+        function.with_argument("k", 2.5);
+        let mut band = VrtBand::derived(GdalDataType::Float32, &function);
+        band.with_source_transfer_type(GdalDataType::Float64)
+            .with_no_data_value(-1.0)
+            .with_source(&VrtSource::new("a&b.tif", 2));
+        let mut vrt = VrtDataset::new(4, 3);
+        vrt.with_geo_transform(&[10.0, 1.0, 0.0, 20.0, 0.0, -1.0])
+            .with_band(&band);
+        assert_eq!(
+            vrt.to_xml(),
+            r#"<VRTDataset rasterXSize="4" rasterYSize="3">
+  <GeoTransform>10, 1, 0, 20, 0, -1</GeoTransform>
+  <VRTRasterBand dataType="Float32" band="1" subClass="VRTDerivedRasterBand">
+    <NoDataValue>-1</NoDataValue>
+    <PixelFunctionType>mul</PixelFunctionType>
+    <PixelFunctionArguments k="2.5"/>
+    <SourceTransferType>Float64</SourceTransferType>
+    <SimpleSource>
+      <SourceFilename relativeToVRT="0">a&amp;b.tif</SourceFilename>
+      <SourceBand>2</SourceBand>
+    </SimpleSource>
+  </VRTRasterBand>
+</VRTDataset>
+"#
+        );
+    }
+
+    #[test]
+    fn test_vrt_sources_xml() {
+        let mut source = VrtSource::new("a.tif", 1);
+        source
+            .with_src_window(Window::new((1, 2), (3, 4)))
+            .with_dst_window(Window::new((0, 0), (6, 8)))
+            .with_scale(1.0, 0.5)
+            .with_lut(&[(0.0, 10.0), (1.0, 20.0)])
+            .with_no_data_value(0.0);
+        let mut mask = VrtBand::new(GdalDataType::UInt8);
+        mask.with_source(&VrtSource::new("mask.tif", 1));
+        let mut band = VrtBand::new(GdalDataType::UInt8);
+        band.with_metadata_item("A", "<1>", "")
+            .with_metadata_item("B", "2", "domain")
+            .with_metadata_item("C", "3", "")
+            .with_source(&source)
+            .with_mask_band(&mask);
+        let mut vrt = VrtDataset::new(6, 8);
+        vrt.with_metadata_item("D", "4", "")
+            .with_band(&band)
+            .with_mask_band(&mask);
+        assert_eq!(
+            vrt.to_xml(),
+            r#"<VRTDataset rasterXSize="6" rasterYSize="8">
+  <Metadata>
+    <MDI key="D">4</MDI>
+  </Metadata>
+  <VRTRasterBand dataType="Byte" band="1">
+    <Metadata>
+      <MDI key="A">&lt;1&gt;</MDI>
+      <MDI key="C">3</MDI>
+    </Metadata>
+    <Metadata domain="domain">
+      <MDI key="B">2</MDI>
+    </Metadata>
+    <ComplexSource>
+      <SourceFilename relativeToVRT="0">a.tif</SourceFilename>
+      <SourceBand>1</SourceBand>
+      <SrcRect xOff="1" yOff="2" xSize="3" ySize="4"/>
+      <DstRect xOff="0" yOff="0" xSize="6" ySize="8"/>
+      <ScaleOffset>1</ScaleOffset>
+      <ScaleRatio>0.5</ScaleRatio>
+      <LUT>0:10,1:20</LUT>
+      <NODATA>0</NODATA>
+    </ComplexSource>
+    <MaskBand>
+      <VRTRasterBand dataType="Byte">
+        <SimpleSource>
+          <SourceFilename relativeToVRT="0">mask.tif</SourceFilename>
+          <SourceBand>1</SourceBand>
+        </SimpleSource>
+      </VRTRasterBand>
+    </MaskBand>
+  </VRTRasterBand>
+  <MaskBand>
+    <VRTRasterBand dataType="Byte">
+      <SimpleSource>
+        <SourceFilename relativeToVRT="0">mask.tif</SourceFilename>
+        <SourceBand>1</SourceBand>
+      </SimpleSource>
+    </VRTRasterBand>
+  </MaskBand>
+</VRTDataset>
+"#
+        );
+    }
+
+    #[test]
+    fn test_vrt_sources() {
+        let path = "/vsimem/test_vrt_sources.tif";
+        create_source(path);
+
+        // the 2x2 pixels from (1, 1) of the first band: 5, 6, 9, 10
+        let window = Window::new((1, 1), (2, 2));
+        let mut simple = VrtSource::new(path, 1);
+        simple.with_src_window(window);
+        let mut scaled = simple.clone();
+        scaled.with_scale(1.0, 2.0);
+        let mut lut = simple.clone();
+        lut.with_lut(&[(0.0, 0.0), (20.0, 100.0)]);
+        let mut mask = simple.clone();
+        mask.with_lut(&[(0.0, 0.0), (5.5, 0.0), (6.0, 255.0), (20.0, 255.0)]);
+
+        let mut vrt = VrtDataset::new(2, 2);
+        vrt.with_metadata_item("KEY", "value", "");
+        for source in [&simple, &scaled, &lut] {
+            let mut band = VrtBand::new(GdalDataType::Float32);
+            band.with_source(source);
+            vrt.with_band(&band);
+        }
+        let mut mask_band = VrtBand::new(GdalDataType::UInt8);
+        mask_band.with_source(&mask);
+        vrt.with_mask_band(&mask_band);
+        let dataset = vrt.build().unwrap();
+
+        assert_eq!(dataset.metadata_item("KEY", ""), Some("value".to_string()));
+        let read = |band: &RasterBand| {
+            band.read_as::<f64>((0, 0), (2, 2), (2, 2), None)
+                .unwrap()
+                .data()
+                .to_vec()
+        };
+        let bands = (1..=3)
+            .map(|i| read(&dataset.rasterband(i).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(bands[0], [5.0, 6.0, 9.0, 10.0]);
+        assert_eq!(bands[1], [11.0, 13.0, 19.0, 21.0]);
+        assert_eq!(bands[2], [25.0, 30.0, 45.0, 50.0]);
+
+        let band = dataset.rasterband(1).unwrap();
+        assert!(band.mask_flags().unwrap().is_per_dataset());
+        assert_eq!(
+            read(&band.open_mask_band().unwrap()),
+            [0.0, 255.0, 255.0, 255.0]
+        );
+
+        drop(dataset);
+        DriverManager::get_driver_by_name("GTiff")
+            .unwrap()
+            .delete(path)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_builtin_pixel_function() {
+        let path = "/vsimem/test_builtin_pixel_function.tif";
+        create_source(path);
+
+        let mut function = PixelFunction::sum();
+        function.with_argument("k", 0.5);
+        let mut band = VrtBand::derived(GdalDataType::Float64, &function);
+        band.with_source(&VrtSource::new(path, 1))
+            .with_source(&VrtSource::new(path, 2));
+        let mut vrt = VrtDataset::new(4, 3);
+        vrt.with_band(&band);
+        let dataset = vrt.build().unwrap();
+
+        let expected = (0..12).map(|i| 3.0 * i as f64 + 1.5).collect::<Vec<_>>();
+        assert_eq!(read_band(&dataset), expected);
+
+        drop(dataset);
+        DriverManager::get_driver_by_name("GTiff")
+            .unwrap()
+            .delete(path)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_rust_pixel_function() {
+        let path = "/vsimem/test_rust_pixel_function.tif";
+        create_source(path);
+
+        register_pixel_function("gdal_rs_test_difference", |sources, output| {
+            let (a, b) = (sources[0].data(), sources[1].data());
+            for (i, value) in output.data_mut().iter_mut().enumerate() {
+                *value = b[i] - a[i];
+            }
+            Ok(())
+        })
+        .unwrap();
+        register_pixel_function("gdal_rs_test_error", |_, _| {
+            Err(GdalError::BadArgument("test error".to_string()))
+        })
+        .unwrap();
+
+        let mut band = VrtBand::derived(
+            GdalDataType::Int16,
+            &PixelFunction::new("gdal_rs_test_difference"),
+        );
+        band.with_source(&VrtSource::new(path, 1))
+            .with_source(&VrtSource::new(path, 2));
+        let mut vrt = VrtDataset::new(4, 3);
+        vrt.with_band(&band);
+        let dataset = vrt.build().unwrap();
+        let expected = (0..12).map(|i| i as f64 + 1.0).collect::<Vec<_>>();
+        assert_eq!(read_band(&dataset), expected);
+
+        let mut band = VrtBand::derived(
+            GdalDataType::Int16,
+            &PixelFunction::new("gdal_rs_test_error"),
+        );
+        band.with_source(&VrtSource::new(path, 1));
+        let mut vrt = VrtDataset::new(4, 3);
+        vrt.with_band(&band);
+        let failing = vrt.build().unwrap();
+        let result = failing
+            .rasterband(1)
+            .unwrap()
+            .read_as::<f64>((0, 0), (4, 3), (4, 3), None);
+        assert!(result.is_err());
+
+        drop(failing);
+        drop(dataset);
+        DriverManager::get_driver_by_name("GTiff")
+            .unwrap()
+            .delete(path)
+            .unwrap();
+    }
+}
@@ -1,10 +1,16 @@
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::ptr::{null, null_mut};
 
 use gdal_sys::{CPLErr, GDALResampleAlg};
 
 use crate::dataset::Dataset;
 use crate::errors::*;
-use crate::utils::_last_cpl_err;
+use crate::raster::{Transformer, TransformerOptions};
+use crate::spatial_ref::SpatialRef;
+use crate::utils::{_last_cpl_err, _last_null_pointer_err};
+use crate::GeoTransform;
 
 pub fn reproject(src: &Dataset, dst: &Dataset) -> Result<()> {
     let rv = unsafe {
@@ -26,3 +32,252 @@ pub fn reproject(src: &Dataset, dst: &Dataset) -> Result<()> {
     }
     Ok(())
 }
+
+/// Resampling algorithms used when warping.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum WarpResampleAlg {
+    /// Nearest neighbour
+    NearestNeighbour = GDALResampleAlg::GRA_NearestNeighbour,
+    /// Bilinear (2x2 kernel)
+    Bilinear = GDALResampleAlg::GRA_Bilinear,
+    /// Cubic Convolution Approximation (4x4 kernel)
+    Cubic = GDALResampleAlg::GRA_Cubic,
+    /// Cubic B-Spline Approximation (4x4 kernel)
+    CubicSpline = GDALResampleAlg::GRA_CubicSpline,
+    /// Lanczos windowed sinc interpolation (6x6 kernel)
+    Lanczos = GDALResampleAlg::GRA_Lanczos,
+    /// Average
+    Average = GDALResampleAlg::GRA_Average,
+    /// Mode (selects the value which appears most often of all the sampled points)
+    Mode = GDALResampleAlg::GRA_Mode,
+    /// Maximum of all the non-nodata contributing pixels
+    Max = GDALResampleAlg::GRA_Max,
+    /// Minimum of all the non-nodata contributing pixels
+    Min = GDALResampleAlg::GRA_Min,
+    /// Median of all the non-nodata contributing pixels
+    Med = GDALResampleAlg::GRA_Med,
+    /// First quartile of all the non-nodata contributing pixels
+    Q1 = GDALResampleAlg::GRA_Q1,
+    /// Third quartile of all the non-nodata contributing pixels
+    Q3 = GDALResampleAlg::GRA_Q3,
+    /// Weighted sum of all the non-nodata contributing pixels
+    Sum = GDALResampleAlg::GRA_Sum,
+    /// Root mean square of all the non-nodata contributing pixels
+    Rms = GDALResampleAlg::GRA_RMS,
+}
+
+impl WarpResampleAlg {
+    /// Convert Rust enum discriminant to value expected by the warp functions.
+    pub fn to_gdal(&self) -> GDALResampleAlg::Type {
+        *self as GDALResampleAlg::Type
+    }
+}
+
+/// A virtual dataset that warps another dataset on the fly, returned by
+/// [`Dataset::auto_warped_vrt`] and [`Dataset::warped_vrt`].
+///
+/// It reads from the source dataset, so it can't outlive it.
+#[derive(Debug)]
+pub struct WarpedDataset<'a> {
+    dataset: Dataset,
+    _source: PhantomData<&'a Dataset>,
+}
+
+impl WarpedDataset<'_> {
+    /// Set the spatial reference of the warped dataset, e.g. the target spatial reference of the
+    /// transformer passed to [`Dataset::warped_vrt`].
+    pub fn set_spatial_ref(&mut self, spatial_ref: &SpatialRef) -> Result<()> {
+        self.dataset.set_spatial_ref(spatial_ref)
+    }
+}
+
+impl Deref for WarpedDataset<'_> {
+    type Target = Dataset;
+
+    fn deref(&self) -> &Self::Target {
+        &self.dataset
+    }
+}
+
+impl Dataset {
+    /// Create a virtual dataset that reprojects this dataset on the fly, with a size and extent
+    /// chosen like `gdalwarp` does.
+    ///
+    /// # Arguments
+    /// * `dst_spatial_ref` - the target spatial reference, or `None` to keep the one of this
+    ///   dataset, e.g. to georeference an image that only has GCPs or RPCs
+    /// * `resample_alg` - the resampling algorithm
+    /// * `max_error` - the maximum error, in pixels, of the approximate transformer, or `0.0`
+    ///   to transform every pixel exactly
+    /// * `options` - how pixels map to coordinates in this dataset
+    ///
+    /// See: [`GDALAutoCreateWarpedVRTEx`](https://gdal.org/api/gdalwarp_cpp.html#_CPPv425GDALAutoCreateWarpedVRTEx12GDALDatasetHPKcPKc15GDALResampleAlgdPK15GDALWarpOptions12CSLConstList)
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::raster::{TransformerOptions, WarpResampleAlg};
+    /// use gdal::spatial_ref::SpatialRef;
+    /// use gdal::Dataset;
+    ///
+    /// let dataset = Dataset::open("fixtures/tinymarble.tif")?;
+    /// let web_mercator = SpatialRef::from_epsg(3857)?;
+    /// let warped = dataset.auto_warped_vrt(
+    ///     Some(&web_mercator),
+    ///     WarpResampleAlg::Bilinear,
+    ///     0.125,
+    ///     &TransformerOptions::new(),
+    /// )?;
+    /// let data = warped.rasterband(1)?.read_band_as::<u8>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn auto_warped_vrt(
+        &self,
+        dst_spatial_ref: Option<&SpatialRef>,
+        resample_alg: WarpResampleAlg,
+        max_error: f64,
+        options: &TransformerOptions,
+    ) -> Result<WarpedDataset<'_>> {
+        let dst_wkt = dst_spatial_ref
+            .map(|spatial_ref| spatial_ref.to_wkt().map(CString::new))
+            .transpose()?
+            .transpose()?;
+        let c_dataset = unsafe {
+            gdal_sys::GDALAutoCreateWarpedVRTEx(
+                self.c_dataset(),
+                null(),
+                dst_wkt.as_ref().map_or(null(), |wkt| wkt.as_ptr()),
+                resample_alg.to_gdal(),
+                max_error,
+                null(),
+                options.as_ptr(),
+            )
+        };
+        if c_dataset.is_null() {
+            return Err(_last_null_pointer_err("GDALAutoCreateWarpedVRTEx"));
+        }
+        Ok(WarpedDataset {
+            dataset: unsafe { Dataset::from_c_dataset(c_dataset) },
+            _source: PhantomData,
+        })
+    }
+
+    /// Create a virtual dataset of the given size and geo-transform, that warps this dataset on
+    /// the fly using `transformer`.
+    ///
+    /// The transformer must map the pixels of this dataset to georeferenced coordinates; its
+    /// destination geo-transform is replaced by `geo_transform`. The size and geo-transform can
+    /// be obtained from [`Transformer::suggested_warp_output`].
+    ///
+    /// The warped dataset has no spatial reference; use [`WarpedDataset::set_spatial_ref`] to
+    /// set it.
+    ///
+    /// See: [`GDALCreateWarpedVRT`](https://gdal.org/api/gdalwarp_cpp.html#_CPPv419GDALCreateWarpedVRT12GDALDatasetHiiPdP15GDALWarpOptions)
+    pub fn warped_vrt(
+        &self,
+        size: (usize, usize),
+        geo_transform: &GeoTransform,
+        mut transformer: Transformer,
+        resample_alg: WarpResampleAlg,
+    ) -> Result<WarpedDataset<'_>> {
+        let (cols, rows) = (size.0.try_into()?, size.1.try_into()?);
+        let band_count = self.raster_count().try_into()?;
+        transformer.set_dst_geo_transform(geo_transform);
+        let mut geo_transform = *geo_transform;
+
+        let c_dataset = unsafe {
+            let warp_options = gdal_sys::GDALCreateWarpOptions();
+            (*warp_options).hSrcDS = self.c_dataset();
+            (*warp_options).eResampleAlg = resample_alg.to_gdal();
+            gdal_sys::GDALWarpInitDefaultBandMapping(warp_options, band_count);
+            (*warp_options).pfnTransformer = Some(gdal_sys::GDALGenImgProjTransform);
+            // The warped dataset takes ownership of the transformer. It is leaked on failure,
+            // since it may already have been destroyed then.
+            (*warp_options).pTransformerArg = transformer.into_c_transformer();
+
+            let c_dataset = gdal_sys::GDALCreateWarpedVRT(
+                self.c_dataset(),
+                cols,
+                rows,
+                geo_transform.as_mut_ptr(),
+                warp_options,
+            );
+            gdal_sys::GDALDestroyWarpOptions(warp_options);
+            c_dataset
+        };
+        if c_dataset.is_null() {
+            return Err(_last_null_pointer_err("GDALCreateWarpedVRT"));
+        }
+        Ok(WarpedDataset {
+            dataset: unsafe { Dataset::from_c_dataset(c_dataset) },
+            _source: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::TransformerMethod;
+    use crate::test_utils::fixture;
+
+    #[test]
+    fn test_auto_warped_vrt() {
+        let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let web_mercator = SpatialRef::from_epsg(3857).unwrap();
+        let warped = dataset
+            .auto_warped_vrt(
+                Some(&web_mercator),
+                WarpResampleAlg::NearestNeighbour,
+                0.0,
+                &TransformerOptions::new(),
+            )
+            .unwrap();
+        let (cols, rows) = warped.raster_size();
+        assert!(cols > 0 && rows > 0);
+        assert_eq!(warped.raster_count(), dataset.raster_count());
+        assert_eq!(warped.spatial_ref().unwrap().auth_code().unwrap(), 3857);
+        warped.rasterband(1).unwrap().read_band_as::<u8>().unwrap();
+    }
+
+    #[test]
+    fn test_auto_warped_vrt_gcps() {
+        let dataset = Dataset::open(fixture("gcp.tif")).unwrap();
+        let mut options = TransformerOptions::new();
+        options
+            .with_method(TransformerMethod::GcpPolynomial(Some(1)))
+            .unwrap();
+        let warped = dataset
+            .auto_warped_vrt(None, WarpResampleAlg::NearestNeighbour, 0.0, &options)
+            .unwrap();
+        let gt = warped.geo_transform().unwrap();
+        assert_eq!(gt[2], 0.0);
+        assert_eq!(gt[4], 0.0);
+        assert_eq!(warped.spatial_ref().unwrap().auth_code().unwrap(), 4326);
+    }
+
+    #[test]
+    fn test_warped_vrt() {
+        let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let transformer = Transformer::new(&dataset, None, &TransformerOptions::new()).unwrap();
+        let (size, gt) = transformer.suggested_warp_output(&dataset).unwrap();
+        assert_eq!(size, dataset.raster_size());
+
+        // warping to the same grid doesn't change the data
+        let mut warped = dataset
+            .warped_vrt(size, &gt, transformer, WarpResampleAlg::NearestNeighbour)
+            .unwrap();
+        warped
+            .set_spatial_ref(&dataset.spatial_ref().unwrap())
+            .unwrap();
+        assert_eq!(warped.raster_size(), dataset.raster_size());
+        assert_eq!(warped.geo_transform().unwrap(), gt);
+        assert_eq!(
+            warped.rasterband(1).unwrap().read_band_as::<u8>().unwrap(),
+            dataset.rasterband(1).unwrap().read_band_as::<u8>().unwrap()
+        );
+    }
+}
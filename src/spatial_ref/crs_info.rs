@@ -0,0 +1,254 @@
+use std::ffi::{c_int, CString};
+use std::ptr;
+
+use gdal_sys::OSRCRSType;
+
+use crate::errors::*;
+use crate::spatial_ref::{AreaOfUse, SpatialRef};
+use crate::utils::_string;
+
+/// Type of a CRS in the PROJ database.
+///
+/// See: [`OSRCRSType`](https://gdal.org/api/ogr_srs_api.html#_CPPv410OSRCRSType)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CrsType {
+    Geographic2D,
+    Geographic3D,
+    Geocentric,
+    Projected,
+    Vertical,
+    Compound,
+    Other,
+}
+
+impl From<OSRCRSType::Type> for CrsType {
+    fn from(value: OSRCRSType::Type) -> Self {
+        use OSRCRSType::*;
+
+        match value {
+            OSR_CRS_TYPE_GEOGRAPHIC_2D => Self::Geographic2D,
+            OSR_CRS_TYPE_GEOGRAPHIC_3D => Self::Geographic3D,
+            OSR_CRS_TYPE_GEOCENTRIC => Self::Geocentric,
+            OSR_CRS_TYPE_PROJECTED => Self::Projected,
+            OSR_CRS_TYPE_VERTICAL => Self::Vertical,
+            OSR_CRS_TYPE_COMPOUND => Self::Compound,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Description of a CRS in the PROJ database.
+///
+/// See [`crs_info_list`].
+#[derive(Debug, Clone)]
+pub struct CrsInfo {
+    /// Authority name, e.g. `"EPSG"`.
+    pub auth_name: String,
+    /// Object code within the authority, e.g. `"4326"`.
+    pub code: String,
+    /// Object name.
+    pub name: String,
+    /// Object type.
+    pub crs_type: CrsType,
+    /// Whether the object is deprecated.
+    pub deprecated: bool,
+    /// The area of use, or `None` if it is unknown.
+    pub area_of_use: Option<AreaOfUse>,
+    /// Name of the projection method for a projected CRS.
+    pub projection_method: Option<String>,
+}
+
+impl CrsInfo {
+    /// Returns the `"AUTH:CODE"` identifier of this CRS.
+    pub fn authority(&self) -> String {
+        format!("{}:{}", self.auth_name, self.code)
+    }
+
+    /// Instantiates this CRS from the database.
+    pub fn to_spatial_ref(&self) -> Result<SpatialRef> {
+        SpatialRef::from_definition(&self.authority())
+    }
+
+    /// Returns `true` if this CRS passes all the criteria of `filter`.
+    pub fn matches(&self, filter: &CrsInfoFilter) -> bool {
+        if self.deprecated && !filter.include_deprecated {
+            return false;
+        }
+        if let Some(crs_types) = filter.crs_types {
+            if !crs_types.contains(&self.crs_type) {
+                return false;
+            }
+        }
+        if let Some(name) = filter.name {
+            if !self.name.to_lowercase().contains(&name.to_lowercase()) {
+                return false;
+            }
+        }
+        if let Some(bbox) = filter.area_of_interest {
+            match &self.area_of_use {
+                Some(area_of_use) => {
+                    if !intersects(area_of_use, &bbox) {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Criteria for selecting entries in [`crs_info_list`].
+///
+/// The default filter keeps every CRS that is not deprecated.
+#[derive(Debug, Clone, Default)]
+pub struct CrsInfoFilter<'a> {
+    /// Only keep CRSs whose name contains this string (case-insensitive).
+    pub name: Option<&'a str>,
+    /// Only keep CRSs of one of these types.
+    pub crs_types: Option<&'a [CrsType]>,
+    /// Only keep CRSs whose area of use intersects this `[west, south, east, north]`
+    /// bounding box, in degrees. `west` may be greater than `east` for a box crossing
+    /// the anti-meridian.
+    pub area_of_interest: Option<[f64; 4]>,
+    /// Also keep deprecated CRSs.
+    pub include_deprecated: bool,
+}
+
+/// Longitude intervals of a `[west, east]` range, split at the anti-meridian if needed.
+fn lon_intervals(west: f64, east: f64) -> Vec<(f64, f64)> {
+    if west <= east {
+        vec![(west, east)]
+    } else {
+        vec![(west, 180.0), (-180.0, east)]
+    }
+}
+
+fn intersects(area_of_use: &AreaOfUse, bbox: &[f64; 4]) -> bool {
+    let [west, south, east, north] = *bbox;
+    if area_of_use.north_lat_degree < south || area_of_use.south_lat_degree > north {
+        return false;
+    }
+    let a = lon_intervals(area_of_use.west_lon_degree, area_of_use.east_lon_degree);
+    let b = lon_intervals(west, east);
+    a.iter()
+        .any(|(aw, ae)| b.iter().any(|(bw, be)| aw <= be && bw <= ae))
+}
+
+/// List the CRSs of the PROJ database that pass `filter`.
+///
+/// # Arguments
+/// * `auth_name` - authority name, e.g. `"EPSG"`, or `None` for all authorities
+/// * `filter` - selection criteria, see [`CrsInfoFilter`]
+///
+/// See: [`OSRGetCRSInfoListFromDatabase`](https://gdal.org/api/ogr_srs_api.html#_CPPv429OSRGetCRSInfoListFromDatabasePKcPK20OSRCRSListParametersPi)
+pub fn crs_info_list(auth_name: Option<&str>, filter: &CrsInfoFilter) -> Result<Vec<CrsInfo>> {
+    let c_auth_name = auth_name.map(CString::new).transpose()?;
+    let mut n_results: c_int = 0;
+    let c_list = unsafe {
+        gdal_sys::OSRGetCRSInfoListFromDatabase(
+            c_auth_name.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+            ptr::null(),
+            &mut n_results,
+        )
+    };
+    if c_list.is_null() {
+        return Ok(Vec::new());
+    }
+
+    let mut list = Vec::new();
+    for i in 0..n_results as usize {
+        let c_info = unsafe { &**c_list.add(i) };
+        let area_of_use = if c_info.bBboxValid != 0 {
+            Some(AreaOfUse {
+                west_lon_degree: c_info.dfWestLongitudeDeg,
+                south_lat_degree: c_info.dfSouthLatitudeDeg,
+                east_lon_degree: c_info.dfEastLongitudeDeg,
+                north_lat_degree: c_info.dfNorthLatitudeDeg,
+                name: _string(c_info.pszAreaName).unwrap_or_default(),
+            })
+        } else {
+            None
+        };
+        let info = CrsInfo {
+            auth_name: _string(c_info.pszAuthName).unwrap_or_default(),
+            code: _string(c_info.pszCode).unwrap_or_default(),
+            name: _string(c_info.pszName).unwrap_or_default(),
+            crs_type: c_info.eType.into(),
+            deprecated: c_info.bDeprecated != 0,
+            area_of_use,
+            projection_method: _string(c_info.pszProjectionMethod),
+        };
+        if info.matches(filter) {
+            list.push(info);
+        }
+    }
+    unsafe { gdal_sys::OSRDestroyCRSInfoList(c_list) };
+    Ok(list)
+}
+
+/// List the authorities known to the PROJ database, e.g. `"EPSG"`, `"ESRI"` or `"IGNF"`.
+///
+/// See: [`OSRGetAuthorityListFromDatabase`](https://gdal.org/api/ogr_srs_api.html#_CPPv431OSRGetAuthorityListFromDatabasev)
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_10)))]
+pub fn authority_list() -> Vec<String> {
+    let c_list = unsafe { gdal_sys::OSRGetAuthorityListFromDatabase() };
+    if c_list.is_null() {
+        return Vec::new();
+    }
+    let list = crate::utils::_string_array(c_list);
+    unsafe { gdal_sys::CSLDestroy(c_list) };
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_10)))]
+    #[test]
+    fn list_authorities() {
+        let authorities = authority_list();
+        assert!(authorities.iter().any(|a| a == "EPSG"));
+    }
+
+    #[test]
+    fn list_epsg() {
+        let all = crs_info_list(Some("EPSG"), &CrsInfoFilter::default()).unwrap();
+        assert!(all.len() > 1000);
+        assert!(all.iter().all(|info| info.auth_name == "EPSG"));
+        assert!(all.iter().all(|info| !info.deprecated));
+
+        let wgs84 = all.iter().find(|info| info.code == "4326").unwrap();
+        assert_eq!(wgs84.name, "WGS 84");
+        assert_eq!(wgs84.crs_type, CrsType::Geographic2D);
+        assert_eq!(
+            wgs84.to_spatial_ref().unwrap(),
+            SpatialRef::from_epsg(4326).unwrap()
+        );
+    }
+
+    #[test]
+    fn filter_epsg() {
+        let filter = CrsInfoFilter {
+            name: Some("utm zone 32n"),
+            crs_types: Some(&[CrsType::Projected]),
+            // around Stuttgart
+            area_of_interest: Some([9.0, 48.5, 9.5, 49.0]),
+            include_deprecated: false,
+        };
+        let list = crs_info_list(Some("EPSG"), &filter).unwrap();
+        assert!(list.iter().any(|info| info.code == "32632"));
+        assert!(list
+            .iter()
+            .all(|info| info.crs_type == CrsType::Projected && info.projection_method.is_some()));
+
+        // UTM zone 32N doesn't cover New Zealand
+        let filter = CrsInfoFilter {
+            area_of_interest: Some([170.0, -45.0, 175.0, -40.0]),
+            ..filter
+        };
+        let list = crs_info_list(Some("EPSG"), &filter).unwrap();
+        assert!(!list.iter().any(|info| info.code == "32632"));
+    }
+}
@@ -4,6 +4,9 @@
 //!
 //! See also: [OGR Coordinate Reference Systems and Coordinate Transformation Tutorial](https://gdal.org/tutorials/osr_api_tut.html)
 
+mod crs_info;
+#[cfg(feature = "proj")]
+mod operations;
 mod srs;
 mod transform;
 mod transform_opts;
@@ -13,6 +16,12 @@ mod transform_opts;
 /// See [`OGRAxisOrientation`](https://gdal.org/api/ogr_srs_api.html#_CPPv418OGRAxisOrientation).
 pub type AxisOrientationType = gdal_sys::OGRAxisOrientation::Type;
 
-pub use srs::{AxisMappingStrategy, SpatialRef};
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_10)))]
+pub use crs_info::authority_list;
+pub use crs_info::{crs_info_list, CrsInfo, CrsInfoFilter, CrsType};
+#[cfg(feature = "proj")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proj")))]
+pub use operations::{coordinate_operations, CoordinateOperation, GridInfo};
+pub use srs::{AreaOfUse, AxisMappingStrategy, GeogCsParams, SpatialRef, SrsMatch};
 pub use transform::CoordTransform;
 pub use transform_opts::CoordTransformOptions;
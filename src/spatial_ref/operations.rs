@@ -0,0 +1,324 @@
+use std::ffi::{c_char, c_int, CString};
+use std::ptr;
+
+use proj_sys::{PJ, PJ_CONTEXT, PJ_OBJ_LIST, PJ_OPERATION_FACTORY_CONTEXT};
+
+use crate::errors::*;
+use crate::spatial_ref::{AreaOfUse, SpatialRef};
+use crate::utils::{_string, _string_array};
+
+/// A candidate coordinate operation between two CRSs, as found by PROJ.
+///
+/// See [`coordinate_operations`].
+#[derive(Debug, Clone)]
+pub struct CoordinateOperation {
+    /// Operation name, e.g. `"NAD27 to WGS 84 (79)"`.
+    pub name: String,
+    /// Authority name of the operation, e.g. `"EPSG"`, if it comes from the database.
+    pub auth_name: Option<String>,
+    /// Code of the operation within its authority, if it comes from the database.
+    pub code: Option<String>,
+    /// PROJ pipeline of the operation, suitable for
+    /// [`CoordTransformOptions::set_coordinate_operation`][crate::spatial_ref::CoordTransformOptions::set_coordinate_operation],
+    /// or `None` if it cannot be expressed as a PROJ string.
+    pub proj_string: Option<String>,
+    /// Accuracy in meters, or `None` if it is unknown.
+    pub accuracy: Option<f64>,
+    /// The area of use, or `None` if it is unknown.
+    pub area_of_use: Option<AreaOfUse>,
+    /// Grids required by the operation.
+    pub grids: Vec<GridInfo>,
+    /// Whether PROJ can instantiate the operation, i.e. all its grids are available.
+    pub is_instantiable: bool,
+    /// Whether the operation contains a ballpark transformation.
+    pub has_ballpark_transformation: bool,
+}
+
+impl CoordinateOperation {
+    /// Returns the grids required by this operation that are not available locally.
+    pub fn missing_grids(&self) -> impl Iterator<Item = &GridInfo> {
+        self.grids.iter().filter(|grid| !grid.available)
+    }
+}
+
+/// A grid used by a [`CoordinateOperation`].
+#[derive(Debug, Clone)]
+pub struct GridInfo {
+    /// Grid name, as referenced by the operation.
+    pub short_name: String,
+    /// Full path of the grid file, if it was found.
+    pub full_name: Option<String>,
+    /// Name of the package containing the grid, if any.
+    pub package_name: Option<String>,
+    /// URL from which the grid or its package can be downloaded, if any.
+    pub url: Option<String>,
+    /// Whether `url` points directly to the grid.
+    pub direct_download: bool,
+    /// Whether the grid is released under an open license.
+    pub open_license: bool,
+    /// Whether the grid is available locally.
+    pub available: bool,
+}
+
+/// List the candidate coordinate operations from `source` to `target`.
+///
+/// Operations are sorted by PROJ from the most to the least relevant, taking into account
+/// their accuracy, area of use and grid availability. Operations whose grids are missing are
+/// still listed, so that the required grids can be reported or downloaded.
+///
+/// The PROJ database and grids are looked up using the search paths configured in GDAL.
+///
+/// This requires the `proj` feature, which calls PROJ through `proj-sys`. `proj-sys` links to
+/// the system PROJ if `pkg-config` finds version 9.6.2 or later, and otherwise builds and
+/// statically links its own copy of PROJ 9.6.2. As that PROJ still opens the `proj.db` used by
+/// GDAL, and PROJ rejects databases with an older layout, this function returns an error when
+/// GDAL uses an older PROJ, e.g. PROJ 9.4 with the GDAL 3.8 packages of Ubuntu 24.04. Use it
+/// with a GDAL built against PROJ 9.6.2 or later, ideally the same installation `proj-sys`
+/// links to.
+///
+/// See: [`proj_create_operations`](https://proj.org/development/reference/functions.html#c.proj_create_operations)
+pub fn coordinate_operations(
+    source: &SpatialRef,
+    target: &SpatialRef,
+) -> Result<Vec<CoordinateOperation>> {
+    let ctx = ProjContext::new()?;
+    let c_source = ctx.create_crs(source)?;
+    let c_target = ctx.create_crs(target)?;
+
+    let factory = unsafe { proj_sys::proj_create_operation_factory_context(ctx.0, ptr::null()) };
+    if factory.is_null() {
+        return Err(ctx.last_error("proj_create_operation_factory_context"));
+    }
+    let factory = FactoryContext(factory);
+    unsafe {
+        proj_sys::proj_operation_factory_context_set_grid_availability_use(
+            ctx.0,
+            factory.0,
+            proj_sys::PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_USED_FOR_SORTING,
+        );
+        proj_sys::proj_operation_factory_context_set_spatial_criterion(
+            ctx.0,
+            factory.0,
+            proj_sys::PROJ_SPATIAL_CRITERION_PROJ_SPATIAL_CRITERION_PARTIAL_INTERSECTION,
+        );
+    }
+
+    let c_list =
+        unsafe { proj_sys::proj_create_operations(ctx.0, c_source.0, c_target.0, factory.0) };
+    if c_list.is_null() {
+        return Err(ctx.last_error("proj_create_operations"));
+    }
+    let c_list = ObjList(c_list);
+
+    let count = unsafe { proj_sys::proj_list_get_count(c_list.0) };
+    let mut operations = Vec::with_capacity(count.max(0) as usize);
+    for i in 0..count {
+        let c_op = unsafe { proj_sys::proj_list_get(ctx.0, c_list.0, i) };
+        if c_op.is_null() {
+            return Err(ctx.last_error("proj_list_get"));
+        }
+        operations.push(ctx.operation(&Pj(c_op)));
+    }
+    Ok(operations)
+}
+
+/// A PROJ context using the same search paths as GDAL.
+struct ProjContext(*mut PJ_CONTEXT);
+
+impl ProjContext {
+    fn new() -> Result<Self> {
+        let ctx = unsafe { proj_sys::proj_context_create() };
+        if ctx.is_null() {
+            return Err(GdalError::ProjError {
+                method_name: "proj_context_create",
+                msg: String::new(),
+            });
+        }
+        let ctx = ProjContext(ctx);
+
+        let c_paths = unsafe { gdal_sys::OSRGetPROJSearchPaths() };
+        if !c_paths.is_null() {
+            let paths = _string_array(c_paths);
+            unsafe { gdal_sys::CSLDestroy(c_paths) };
+            let paths = paths
+                .into_iter()
+                .map(CString::new)
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            let c_paths: Vec<*const c_char> = paths.iter().map(|p| p.as_ptr()).collect();
+            unsafe {
+                proj_sys::proj_context_set_search_paths(
+                    ctx.0,
+                    c_paths.len() as c_int,
+                    c_paths.as_ptr(),
+                )
+            };
+        }
+
+        Ok(ctx)
+    }
+
+    fn create_crs(&self, spatial_ref: &SpatialRef) -> Result<Pj> {
+        let c_definition = CString::new(spatial_ref.to_projjson()?)?;
+        let c_crs = unsafe { proj_sys::proj_create(self.0, c_definition.as_ptr()) };
+        if c_crs.is_null() {
+            return Err(self.last_error("proj_create"));
+        }
+        Ok(Pj(c_crs))
+    }
+
+    fn operation(&self, op: &Pj) -> CoordinateOperation {
+        let accuracy = unsafe { proj_sys::proj_coordoperation_get_accuracy(self.0, op.0) };
+
+        let mut c_area_name: *const c_char = ptr::null();
+        let (mut w_long, mut s_lat, mut e_long, mut n_lat) = (0.0, 0.0, 0.0, 0.0);
+        let has_area_of_use = unsafe {
+            proj_sys::proj_get_area_of_use(
+                self.0,
+                op.0,
+                &mut w_long,
+                &mut s_lat,
+                &mut e_long,
+                &mut n_lat,
+                &mut c_area_name,
+            ) == 1
+        };
+        let area_of_use = has_area_of_use.then(|| AreaOfUse {
+            west_lon_degree: w_long,
+            south_lat_degree: s_lat,
+            east_lon_degree: e_long,
+            north_lat_degree: n_lat,
+            name: _string(c_area_name).unwrap_or_default(),
+        });
+
+        let grid_count = unsafe { proj_sys::proj_coordoperation_get_grid_used_count(self.0, op.0) };
+        let grids = (0..grid_count).filter_map(|i| self.grid(op, i)).collect();
+
+        CoordinateOperation {
+            name: _string(unsafe { proj_sys::proj_get_name(op.0) }).unwrap_or_default(),
+            auth_name: _string(unsafe { proj_sys::proj_get_id_auth_name(op.0, 0) }),
+            code: _string(unsafe { proj_sys::proj_get_id_code(op.0, 0) }),
+            proj_string: _string(unsafe {
+                proj_sys::proj_as_proj_string(
+                    self.0,
+                    op.0,
+                    proj_sys::PJ_PROJ_STRING_TYPE_PJ_PROJ_5,
+                    ptr::null(),
+                )
+            }),
+            accuracy: (accuracy >= 0.0).then_some(accuracy),
+            area_of_use,
+            grids,
+            is_instantiable: unsafe {
+                proj_sys::proj_coordoperation_is_instantiable(self.0, op.0) == 1
+            },
+            has_ballpark_transformation: unsafe {
+                proj_sys::proj_coordoperation_has_ballpark_transformation(self.0, op.0) == 1
+            },
+        }
+    }
+
+    fn grid(&self, op: &Pj, index: c_int) -> Option<GridInfo> {
+        let mut c_short_name: *const c_char = ptr::null();
+        let mut c_full_name: *const c_char = ptr::null();
+        let mut c_package_name: *const c_char = ptr::null();
+        let mut c_url: *const c_char = ptr::null();
+        let (mut direct_download, mut open_license, mut available) = (0, 0, 0);
+        let ret_val = unsafe {
+            proj_sys::proj_coordoperation_get_grid_used(
+                self.0,
+                op.0,
+                index,
+                &mut c_short_name,
+                &mut c_full_name,
+                &mut c_package_name,
+                &mut c_url,
+                &mut direct_download,
+                &mut open_license,
+                &mut available,
+            )
+        };
+        if ret_val == 0 {
+            return None;
+        }
+        // PROJ reports missing values as empty strings
+        let non_empty = |c_str| _string(c_str).filter(|s| !s.is_empty());
+        Some(GridInfo {
+            short_name: _string(c_short_name).unwrap_or_default(),
+            full_name: non_empty(c_full_name),
+            package_name: non_empty(c_package_name),
+            url: non_empty(c_url),
+            direct_download: direct_download != 0,
+            open_license: open_license != 0,
+            available: available != 0,
+        })
+    }
+
+    fn last_error(&self, method_name: &'static str) -> GdalError {
+        let msg = unsafe {
+            let errno = proj_sys::proj_context_errno(self.0);
+            _string(proj_sys::proj_context_errno_string(self.0, errno))
+        };
+        GdalError::ProjError {
+            method_name,
+            msg: msg.unwrap_or_default(),
+        }
+    }
+}
+
+impl Drop for ProjContext {
+    fn drop(&mut self) {
+        unsafe { proj_sys::proj_context_destroy(self.0) };
+    }
+}
+
+struct Pj(*mut PJ);
+
+impl Drop for Pj {
+    fn drop(&mut self) {
+        unsafe { proj_sys::proj_destroy(self.0) };
+    }
+}
+
+struct FactoryContext(*mut PJ_OPERATION_FACTORY_CONTEXT);
+
+impl Drop for FactoryContext {
+    fn drop(&mut self) {
+        unsafe { proj_sys::proj_operation_factory_context_destroy(self.0) };
+    }
+}
+
+struct ObjList(*mut PJ_OBJ_LIST);
+
+impl Drop for ObjList {
+    fn drop(&mut self) {
+        unsafe { proj_sys::proj_list_destroy(self.0) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nad27_to_wgs84() {
+        let nad27 = SpatialRef::from_epsg(4267).unwrap();
+        let wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        let operations = coordinate_operations(&nad27, &wgs84).unwrap();
+        assert!(operations.len() > 1);
+
+        let op = operations
+            .iter()
+            .find(|op| op.code.as_deref() == Some("1173"))
+            .expect("NAD27 to WGS 84 (4)");
+        assert_eq!(op.auth_name.as_deref(), Some("EPSG"));
+        assert!(op.accuracy.is_some());
+        assert!(op.area_of_use.is_some());
+        assert!(op.grids.is_empty());
+        assert!(op.is_instantiable);
+        assert!(op.proj_string.is_some());
+
+        // NADCON based operations need grids, which may or may not be installed
+        let gridded = operations.iter().find(|op| !op.grids.is_empty()).unwrap();
+        assert!(!gridded.grids[0].short_name.is_empty());
+    }
+}
@@ -283,6 +283,49 @@ impl SpatialRef {
         }
     }
 
+    /// Find CRSs from the database that match this one, ranked by decreasing confidence.
+    ///
+    /// Unlike [`auto_identify_epsg`][Self::auto_identify_epsg], this returns all candidates,
+    /// with their confidence level between 0 and 100. A confidence of 100 means the CRSs are
+    /// equivalent; lower values indicate partial matches, e.g. a matching projection and datum
+    /// with a different name. Matches are returned for any authority known to PROJ.
+    ///
+    /// See: [`OSRFindMatches`](https://gdal.org/api/ogr_srs_api.html#_CPPv414OSRFindMatches20OGRSpatialReferenceHPPcPiPPi)
+    pub fn find_matches(&self) -> Result<Vec<SrsMatch>> {
+        let mut n_entries: c_int = 0;
+        let mut confidences: *mut c_int = ptr::null_mut();
+        let c_matches = unsafe {
+            gdal_sys::OSRFindMatches(self.0, ptr::null_mut(), &mut n_entries, &mut confidences)
+        };
+        if c_matches.is_null() {
+            return Ok(Vec::new());
+        }
+
+        let mut matches = Vec::with_capacity(n_entries as usize);
+        for i in 0..n_entries as usize {
+            let spatial_ref = unsafe { SpatialRef::from_c_obj(*c_matches.add(i)) };
+            let confidence = unsafe { *confidences.add(i) };
+            match spatial_ref {
+                Ok(spatial_ref) => matches.push(SrsMatch {
+                    spatial_ref,
+                    confidence: confidence as u8,
+                }),
+                Err(e) => {
+                    unsafe {
+                        gdal_sys::OSRFreeSRSArray(c_matches);
+                        gdal_sys::VSIFree(confidences.cast::<std::ffi::c_void>());
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        unsafe {
+            gdal_sys::OSRFreeSRSArray(c_matches);
+            gdal_sys::VSIFree(confidences.cast::<std::ffi::c_void>());
+        }
+        Ok(matches)
+    }
+
     pub fn name(&self) -> Option<String> {
         let c_ptr = unsafe { gdal_sys::OSRGetName(self.0) };
         _string(c_ptr)
@@ -343,6 +386,51 @@ impl SpatialRef {
         unsafe { gdal_sys::OSRIsVertical(self.0) == 1 }
     }
 
+    /// Returns `true` if this CRS is a dynamic CRS, i.e. its datum is a dynamic datum
+    /// such as ITRF2014 or WGS 84 realizations, whose coordinates change with time.
+    ///
+    /// See: [`OSRIsDynamic`](https://gdal.org/api/ogr_srs_api.html#_CPPv412OSRIsDynamic20OGRSpatialReferenceH)
+    #[inline]
+    pub fn is_dynamic(&self) -> bool {
+        unsafe { gdal_sys::OSRIsDynamic(self.0) == 1 }
+    }
+
+    /// Returns `true` if this CRS has an associated point motion operation (plate motion model).
+    ///
+    /// See: [`OSRHasPointMotionOperation`](https://gdal.org/api/ogr_srs_api.html#_CPPv426OSRHasPointMotionOperation20OGRSpatialReferenceH)
+    #[inline]
+    pub fn has_point_motion_operation(&self) -> bool {
+        unsafe { gdal_sys::OSRHasPointMotionOperation(self.0) == 1 }
+    }
+
+    /// Set the coordinate epoch, as decimal year (e.g. `2021.3`).
+    ///
+    /// In a dynamic CRS, coordinates of a point on the surface of the Earth may change with
+    /// time. To be unambiguous, the coordinates must always be qualified with the epoch at
+    /// which they are valid. The coordinate epoch is not necessarily the epoch at which the
+    /// observation was collected.
+    ///
+    /// Passing `0.0` unsets the coordinate epoch.
+    ///
+    /// See: [`OSRSetCoordinateEpoch`](https://gdal.org/api/ogr_srs_api.html#_CPPv421OSRSetCoordinateEpoch20OGRSpatialReferenceHd)
+    pub fn set_coordinate_epoch(&mut self, coordinate_epoch: f64) {
+        unsafe { gdal_sys::OSRSetCoordinateEpoch(self.0, coordinate_epoch) };
+    }
+
+    /// Get the coordinate epoch, as decimal year (e.g. `2021.3`).
+    ///
+    /// Returns `None` if no coordinate epoch is set.
+    ///
+    /// See: [`OSRGetCoordinateEpoch`](https://gdal.org/api/ogr_srs_api.html#_CPPv421OSRGetCoordinateEpoch20OGRSpatialReferenceH)
+    pub fn coordinate_epoch(&self) -> Option<f64> {
+        let epoch = unsafe { gdal_sys::OSRGetCoordinateEpoch(self.0) };
+        if epoch > 0.0 {
+            Some(epoch)
+        } else {
+            None
+        }
+    }
+
     pub fn axis_orientation(
         &self,
         target_key: &str,
@@ -567,6 +655,268 @@ impl SpatialRef {
         Ok(_string(c_ptr))
     }
 
+    /// Set the user-visible `PROJCS` name.
+    ///
+    /// This is typically the first step when assembling a projected CRS, followed by
+    /// setting its geographic CRS (e.g. with [`set_well_known_geog_cs`][Self::set_well_known_geog_cs])
+    /// and its projection (e.g. with [`set_tm`][Self::set_tm]).
+    ///
+    /// See: [`OSRSetProjCS`](https://gdal.org/api/ogr_srs_api.html#_CPPv412OSRSetProjCS20OGRSpatialReferenceHPKc)
+    pub fn set_proj_cs(&mut self, name: &str) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let rv = unsafe { gdal_sys::OSRSetProjCS(self.0, c_name.as_ptr()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetProjCS",
+            });
+        }
+        Ok(())
+    }
+
+    /// Set the geographic CRS to one of the well known ones: `"WGS84"`, `"WGS72"`, `"NAD27"`,
+    /// `"NAD83"`, or `"EPSG:n"` for any geographic CRS in the EPSG database.
+    ///
+    /// See: [`OSRSetWellKnownGeogCS`](https://gdal.org/api/ogr_srs_api.html#_CPPv421OSRSetWellKnownGeogCS20OGRSpatialReferenceHPKc)
+    pub fn set_well_known_geog_cs(&mut self, name: &str) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let rv = unsafe { gdal_sys::OSRSetWellKnownGeogCS(self.0, c_name.as_ptr()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetWellKnownGeogCS",
+            });
+        }
+        Ok(())
+    }
+
+    /// Set the geographic CRS from its datum, ellipsoid, prime meridian and angular units.
+    ///
+    /// See: [`OSRSetGeogCS`](https://gdal.org/api/ogr_srs_api.html#_CPPv412OSRSetGeogCS20OGRSpatialReferenceHPKcPKcPKcddPKcdPKcd)
+    pub fn set_geog_cs(&mut self, geog_cs: &GeogCsParams) -> Result<()> {
+        let c_name = CString::new(geog_cs.name)?;
+        let c_datum_name = CString::new(geog_cs.datum_name)?;
+        let c_ellipsoid_name = CString::new(geog_cs.ellipsoid_name)?;
+        let c_pm_name = geog_cs
+            .prime_meridian
+            .map(|(name, _)| CString::new(name))
+            .transpose()?;
+        let c_units = geog_cs
+            .angular_units
+            .map(|(name, _)| CString::new(name))
+            .transpose()?;
+        let rv = unsafe {
+            gdal_sys::OSRSetGeogCS(
+                self.0,
+                c_name.as_ptr(),
+                c_datum_name.as_ptr(),
+                c_ellipsoid_name.as_ptr(),
+                geog_cs.semi_major,
+                geog_cs.inv_flattening,
+                c_pm_name.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+                geog_cs.prime_meridian.map_or(0.0, |(_, offset)| offset),
+                c_units.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+                geog_cs
+                    .angular_units
+                    .map_or(0.0, |(_, to_radians)| to_radians),
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetGeogCS",
+            });
+        }
+        Ok(())
+    }
+
+    /// Set the linear units for the projection, with their conversion factor to meters.
+    ///
+    /// See: [`OSRSetLinearUnits`](https://gdal.org/api/ogr_srs_api.html#_CPPv417OSRSetLinearUnits20OGRSpatialReferenceHPKcd)
+    pub fn set_linear_units(&mut self, name: &str, to_meters: f64) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let rv = unsafe { gdal_sys::OSRSetLinearUnits(self.0, c_name.as_ptr(), to_meters) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetLinearUnits",
+            });
+        }
+        Ok(())
+    }
+
+    /// Set the projection to Universal Transverse Mercator.
+    ///
+    /// If no geographic CRS is set yet, WGS 84 is assumed.
+    ///
+    /// # Arguments
+    /// * `zone` - UTM zone, from 1 to 60
+    /// * `north` - `true` for the northern hemisphere, `false` for the southern one
+    ///
+    /// See: [`OSRSetUTM`](https://gdal.org/api/ogr_srs_api.html#_CPPv49OSRSetUTM20OGRSpatialReferenceHii)
+    pub fn set_utm(&mut self, zone: i32, north: bool) -> Result<()> {
+        let rv = unsafe { gdal_sys::OSRSetUTM(self.0, zone as c_int, north as c_int) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetUTM",
+            });
+        }
+        Ok(())
+    }
+
+    /// Get the UTM zone and hemisphere, if this is a UTM projection.
+    ///
+    /// Returns `Some((zone, north))`, or `None` if this is not a UTM definition.
+    ///
+    /// See: [`OSRGetUTMZone`](https://gdal.org/api/ogr_srs_api.html#_CPPv413OSRGetUTMZone20OGRSpatialReferenceHPi)
+    pub fn utm_zone(&self) -> Option<(i32, bool)> {
+        let mut north: c_int = 0;
+        let zone = unsafe { gdal_sys::OSRGetUTMZone(self.0, &mut north) };
+        if zone == 0 {
+            None
+        } else {
+            Some((zone, north != 0))
+        }
+    }
+
+    /// Set the projection to Transverse Mercator.
+    ///
+    /// See: [`OSRSetTM`](https://gdal.org/api/ogr_srs_api.html#_CPPv48OSRSetTM20OGRSpatialReferenceHddddd)
+    pub fn set_tm(
+        &mut self,
+        center_lat: f64,
+        center_long: f64,
+        scale: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::OSRSetTM(
+                self.0,
+                center_lat,
+                center_long,
+                scale,
+                false_easting,
+                false_northing,
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetTM",
+            });
+        }
+        Ok(())
+    }
+
+    /// Set the projection to Lambert Conformal Conic with two standard parallels.
+    ///
+    /// See: [`OSRSetLCC`](https://gdal.org/api/ogr_srs_api.html#_CPPv49OSRSetLCC20OGRSpatialReferenceHdddddd)
+    pub fn set_lcc(
+        &mut self,
+        std_parallel_1: f64,
+        std_parallel_2: f64,
+        center_lat: f64,
+        center_long: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::OSRSetLCC(
+                self.0,
+                std_parallel_1,
+                std_parallel_2,
+                center_lat,
+                center_long,
+                false_easting,
+                false_northing,
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetLCC",
+            });
+        }
+        Ok(())
+    }
+
+    /// Set the projection to Albers Conic Equal Area.
+    ///
+    /// See: [`OSRSetACEA`](https://gdal.org/api/ogr_srs_api.html#_CPPv410OSRSetACEA20OGRSpatialReferenceHdddddd)
+    pub fn set_albers(
+        &mut self,
+        std_parallel_1: f64,
+        std_parallel_2: f64,
+        center_lat: f64,
+        center_long: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::OSRSetACEA(
+                self.0,
+                std_parallel_1,
+                std_parallel_2,
+                center_lat,
+                center_long,
+                false_easting,
+                false_northing,
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetACEA",
+            });
+        }
+        Ok(())
+    }
+
+    /// Set the user-visible `VERT_CS` name and vertical datum name.
+    ///
+    /// See: [`OSRSetVertCS`](https://gdal.org/api/ogr_srs_api.html#_CPPv412OSRSetVertCS20OGRSpatialReferenceHPKcPKci)
+    pub fn set_vert_cs(&mut self, name: &str, datum_name: &str) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let c_datum_name = CString::new(datum_name)?;
+        // 2005 (geoid model derived) is the OGC default; it's ignored with PROJ >= 6
+        let rv =
+            unsafe { gdal_sys::OSRSetVertCS(self.0, c_name.as_ptr(), c_datum_name.as_ptr(), 2005) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetVertCS",
+            });
+        }
+        Ok(())
+    }
+
+    /// Set this to a compound CRS made of a `horizontal` (geographic or projected) CRS
+    /// and a `vertical` CRS.
+    ///
+    /// Any existing definition is replaced.
+    ///
+    /// See: [`OSRSetCompoundCS`](https://gdal.org/api/ogr_srs_api.html#_CPPv416OSRSetCompoundCS20OGRSpatialReferenceHPKc20OGRSpatialReferenceH20OGRSpatialReferenceH)
+    pub fn set_compound_cs(
+        &mut self,
+        name: &str,
+        horizontal: &SpatialRef,
+        vertical: &SpatialRef,
+    ) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let rv = unsafe {
+            gdal_sys::OSRSetCompoundCS(self.0, c_name.as_ptr(), horizontal.0, vertical.0)
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetCompoundCS",
+            });
+        }
+        Ok(())
+    }
+
     /// Make a duplicate of the `GEOGCS` node of this [`SpatialRef`].
     ///
     /// Returns an error if the `GEOGCS` node is missing.
@@ -594,6 +944,36 @@ pub struct AreaOfUse {
     pub name: String,
 }
 
+/// A candidate match for a [`SpatialRef`].
+///
+/// See [`find_matches`][SpatialRef::find_matches].
+#[derive(Debug, Clone)]
+pub struct SrsMatch {
+    /// The matching CRS from the database.
+    pub spatial_ref: SpatialRef,
+    /// The confidence level of the match, between 0 and 100.
+    pub confidence: u8,
+}
+
+/// Parameters of a geographic CRS, for [`SpatialRef::set_geog_cs`].
+#[derive(Debug, Clone)]
+pub struct GeogCsParams<'a> {
+    /// The user-visible name of the geographic CRS.
+    pub name: &'a str,
+    /// The datum name, e.g. `"WGS_1984"`.
+    pub datum_name: &'a str,
+    /// The ellipsoid name, e.g. `"WGS 84"`.
+    pub ellipsoid_name: &'a str,
+    /// The ellipsoid semi-major axis, in meters.
+    pub semi_major: f64,
+    /// The ellipsoid inverse flattening, or `0.0` for a sphere.
+    pub inv_flattening: f64,
+    /// The prime meridian name and its offset from Greenwich in degrees, or `None` for Greenwich.
+    pub prime_meridian: Option<(&'a str, f64)>,
+    /// The angular units name and their conversion factor to radians, or `None` for degrees.
+    pub angular_units: Option<(&'a str, f64)>,
+}
+
 /// Data axis to CRS axis mapping strategy.
 ///
 /// See: [`OSRGetAxisMappingStrategy`](https://gdal.org/api/ogrspatialref.html#_CPPv4NK19OGRSpatialReference22GetAxisMappingStrategyEv)
@@ -769,6 +1149,37 @@ mod tests {
         assert_eq!(spatial_ref.auth_code().unwrap(), 32632);
     }
 
+    #[test]
+    fn find_matches() {
+        // same as in `auto_identify`, without the `AUTHORITY["EPSG","32632"]` node
+        let spatial_ref = SpatialRef::from_wkt(
+            r#"
+        PROJCS["WGS_1984_UTM_Zone_32N",
+            GEOGCS["GCS_WGS_1984",
+                DATUM["D_WGS_1984",
+                    SPHEROID["WGS_1984",6378137,298.257223563]],
+                PRIMEM["Greenwich",0],
+                UNIT["Degree",0.017453292519943295]],
+            PROJECTION["Transverse_Mercator"],
+            PARAMETER["latitude_of_origin",0],
+            PARAMETER["central_meridian",9],
+            PARAMETER["scale_factor",0.9996],
+            PARAMETER["false_easting",500000],
+            PARAMETER["false_northing",0],
+            UNIT["Meter",1]]
+    "#,
+        )
+        .unwrap();
+        let matches = spatial_ref.find_matches().unwrap();
+        assert!(!matches.is_empty());
+        let best = &matches[0];
+        assert_eq!(best.spatial_ref.authority().unwrap(), "EPSG:32632");
+        assert!(best.confidence >= 50);
+        assert!(matches
+            .windows(2)
+            .all(|w| w[0].confidence >= w[1].confidence));
+    }
+
     #[test]
     fn axis_mapping_strategy() {
         let mut spatial_ref = SpatialRef::from_epsg(4326).unwrap();
@@ -842,6 +1253,22 @@ mod tests {
         assert!(!spatial_ref_2154.is_derived_geographic());
     }
 
+    #[test]
+    fn coordinate_epoch() {
+        let mut spatial_ref = SpatialRef::from_epsg(7912).unwrap(); // ITRF2014 geographic 3D
+        assert!(spatial_ref.is_dynamic());
+        assert_eq!(spatial_ref.coordinate_epoch(), None);
+
+        spatial_ref.set_coordinate_epoch(2021.3);
+        assert_almost_eq(spatial_ref.coordinate_epoch().unwrap(), 2021.3);
+
+        spatial_ref.set_coordinate_epoch(0.0);
+        assert_eq!(spatial_ref.coordinate_epoch(), None);
+
+        let spatial_ref = SpatialRef::from_epsg(2154).unwrap();
+        assert!(!spatial_ref.is_dynamic());
+    }
+
     #[test]
     fn crs_axis() {
         let spatial_ref = SpatialRef::from_epsg(4326).unwrap();
@@ -940,6 +1367,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_utm() {
+        let mut spatial_ref = SpatialRef::new().unwrap();
+        spatial_ref.set_proj_cs("WGS 84 / UTM zone 32N").unwrap();
+        spatial_ref.set_well_known_geog_cs("WGS84").unwrap();
+        spatial_ref.set_utm(32, true).unwrap();
+        assert_eq!(spatial_ref.utm_zone(), Some((32, true)));
+        assert_eq!(spatial_ref, SpatialRef::from_epsg(32632).unwrap());
+
+        let spatial_ref = SpatialRef::from_epsg(4326).unwrap();
+        assert_eq!(spatial_ref.utm_zone(), None);
+    }
+
+    #[test]
+    fn build_local_grid() {
+        let mut spatial_ref = SpatialRef::new().unwrap();
+        spatial_ref.set_proj_cs("Site grid").unwrap();
+        spatial_ref
+            .set_geog_cs(&GeogCsParams {
+                name: "GRS 1980 based",
+                datum_name: "unknown",
+                ellipsoid_name: "GRS 1980",
+                semi_major: 6_378_137.0,
+                inv_flattening: 298.257222101,
+                prime_meridian: None,
+                angular_units: None,
+            })
+            .unwrap();
+        spatial_ref
+            .set_tm(47.5, 8.25, 1.000_05, 2_000.0, 1_000.0)
+            .unwrap();
+        spatial_ref
+            .set_linear_units("US survey foot", 0.3048006096)
+            .unwrap();
+
+        assert!(spatial_ref.is_projected());
+        assert_eq!(spatial_ref.name().unwrap(), "Site grid");
+        assert_almost_eq(
+            spatial_ref.get_proj_param("scale_factor").unwrap().unwrap(),
+            1.000_05,
+        );
+        assert_almost_eq(spatial_ref.linear_units(), 0.3048006096);
+        assert_almost_eq(spatial_ref.semi_major().unwrap(), 6_378_137.0);
+    }
+
+    #[test]
+    fn build_conic() {
+        let mut lcc = SpatialRef::new().unwrap();
+        lcc.set_proj_cs("LCC").unwrap();
+        lcc.set_well_known_geog_cs("NAD83").unwrap();
+        lcc.set_lcc(33.0, 45.0, 39.0, -96.0, 0.0, 0.0).unwrap();
+        assert_almost_eq(
+            lcc.get_proj_param("standard_parallel_2").unwrap().unwrap(),
+            45.0,
+        );
+
+        let mut albers = SpatialRef::new().unwrap();
+        albers.set_proj_cs("Albers").unwrap();
+        albers.set_well_known_geog_cs("NAD83").unwrap();
+        albers
+            .set_albers(29.5, 45.5, 23.0, -96.0, 0.0, 0.0)
+            .unwrap();
+        assert!(albers.to_proj4().unwrap().contains("+proj=aea"));
+    }
+
+    #[test]
+    fn build_compound() {
+        let horizontal = SpatialRef::from_epsg(4326).unwrap();
+        let mut vertical = SpatialRef::new().unwrap();
+        vertical
+            .set_vert_cs("Local height", "Local vertical datum")
+            .unwrap();
+        assert!(vertical.is_vertical());
+
+        let mut compound = SpatialRef::new().unwrap();
+        compound
+            .set_compound_cs("WGS 84 + Local height", &horizontal, &vertical)
+            .unwrap();
+        assert!(compound.is_compound());
+        assert_eq!(compound.axes_count(), 3);
+    }
+
     #[test]
     fn geog_cs() {
         let spatial_ref = SpatialRef::from_proj4(
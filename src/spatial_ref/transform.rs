@@ -168,21 +168,181 @@ impl CoordTransform {
         if ret_val {
             Ok(())
         } else {
-            let err = _last_cpl_err(CPLErr::CE_Failure);
-            let msg = if let GdalError::CplError { msg, .. } = err {
-                if msg.trim().is_empty() {
-                    None
+            Err(self.invalid_coordinate_range_err())
+        }
+    }
+
+    /// Transform coordinates with an associated time coordinate in place.
+    ///
+    /// This is needed for transformations between dynamic CRSs (like ITRF2014) and static
+    /// ones (like NAD83(CSRS)), where a plate motion model or time-dependent Helmert
+    /// transformation is involved.
+    ///
+    /// # Arguments
+    /// * `x` - slice of x coordinates
+    /// * `y` - slice of y coordinates (must match x in length)
+    /// * `z` - slice of z coordinates, or an empty slice to ignore
+    /// * `t` - slice of time coordinates as decimal years, or an empty slice to ignore
+    ///
+    /// See: [OCTTransform4D](https://gdal.org/api/ogr_srs_api.html#_CPPv414OCTTransform4D28OGRCoordinateTransformationHiPdPdPdPdPi)
+    pub fn transform_coords_with_time(
+        &self,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+        t: &mut [f64],
+    ) -> errors::Result<()> {
+        let nb_coords = x.len();
+        assert_eq!(
+            nb_coords,
+            y.len(),
+            "transform coordinate slices have different lengths: {} != {}",
+            nb_coords,
+            y.len()
+        );
+        for other in [&*z, &*t] {
+            assert!(
+                other.is_empty() || other.len() == nb_coords,
+                "transform coordinate slices have different lengths: {} != {}",
+                nb_coords,
+                other.len()
+            );
+        }
+        let ret_val = unsafe {
+            gdal_sys::OCTTransform4D(
+                self.inner,
+                nb_coords as c_int,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                if z.is_empty() {
+                    null_mut()
                 } else {
-                    Some(msg)
+                    z.as_mut_ptr()
+                },
+                if t.is_empty() {
+                    null_mut()
+                } else {
+                    t.as_mut_ptr()
+                },
+                null_mut(),
+            ) == 1
+        };
+
+        if ret_val {
+            Ok(())
+        } else {
+            Err(self.invalid_coordinate_range_err())
+        }
+    }
+
+    /// Transform coordinates in place, reporting success for each point.
+    ///
+    /// Unlike [`transform_coords`][Self::transform_coords], the failure of some points does not
+    /// fail the whole call: the returned mask holds `true` for each point that was transformed,
+    /// and `false` for each point that could not be. Failed points are left in an unspecified
+    /// state (usually `f64::INFINITY`).
+    ///
+    /// # Arguments
+    /// * `x` - slice of x coordinates
+    /// * `y` - slice of y coordinates (must match x in length)
+    /// * `z` - slice of z coordinates, or an empty slice to ignore
+    /// * `t` - slice of time coordinates as decimal years, or an empty slice to ignore
+    ///
+    /// See: [OCTTransform4D](https://gdal.org/api/ogr_srs_api.html#_CPPv414OCTTransform4D28OGRCoordinateTransformationHiPdPdPdPdPi)
+    pub fn transform_coords_with_mask(
+        &self,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+        t: &mut [f64],
+    ) -> Vec<bool> {
+        let nb_coords = x.len();
+        assert_eq!(
+            nb_coords,
+            y.len(),
+            "transform coordinate slices have different lengths: {} != {}",
+            nb_coords,
+            y.len()
+        );
+        for other in [&*z, &*t] {
+            assert!(
+                other.is_empty() || other.len() == nb_coords,
+                "transform coordinate slices have different lengths: {} != {}",
+                nb_coords,
+                other.len()
+            );
+        }
+        let mut success: Vec<c_int> = vec![0; nb_coords];
+        unsafe {
+            gdal_sys::OCTTransform4D(
+                self.inner,
+                nb_coords as c_int,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                if z.is_empty() {
+                    null_mut()
+                } else {
+                    z.as_mut_ptr()
+                },
+                if t.is_empty() {
+                    null_mut()
+                } else {
+                    t.as_mut_ptr()
+                },
+                success.as_mut_ptr(),
+            )
+        };
+        // failures are reported through the mask; don't leave them behind for later calls
+        unsafe { gdal_sys::CPLErrorReset() };
+        success.into_iter().map(|s| s != 0).collect()
+    }
+
+    /// Transform [`geo_types::Coord`]s in place, reporting success for each point.
+    ///
+    /// Points that fail to transform are left unchanged, and have `false` in the returned mask.
+    /// The coordinates are transformed in batches, so memory use stays bounded for large inputs.
+    ///
+    /// See: [`transform_coords_with_mask`][Self::transform_coords_with_mask]
+    pub fn transform_points(&self, points: &mut [geo_types::Coord<f64>]) -> Vec<bool> {
+        const BATCH_SIZE: usize = 64 * 1024;
+
+        let mut mask = Vec::with_capacity(points.len());
+        let mut xs = Vec::with_capacity(points.len().min(BATCH_SIZE));
+        let mut ys = Vec::with_capacity(points.len().min(BATCH_SIZE));
+        for batch in points.chunks_mut(BATCH_SIZE) {
+            xs.clear();
+            ys.clear();
+            xs.extend(batch.iter().map(|c| c.x));
+            ys.extend(batch.iter().map(|c| c.y));
+            let batch_mask = self.transform_coords_with_mask(&mut xs, &mut ys, &mut [], &mut []);
+            for (i, coord) in batch.iter_mut().enumerate() {
+                if batch_mask[i] {
+                    coord.x = xs[i];
+                    coord.y = ys[i];
                 }
+            }
+            mask.extend(batch_mask);
+        }
+        mask
+    }
+
+    /// Builds a [`GdalError::InvalidCoordinateRange`] from the last CPL error, or returns the
+    /// last error as-is if it is not a [`GdalError::CplError`].
+    fn invalid_coordinate_range_err(&self) -> GdalError {
+        let err = _last_cpl_err(CPLErr::CE_Failure);
+        let msg = if let GdalError::CplError { msg, .. } = err {
+            if msg.trim().is_empty() {
+                None
             } else {
-                return Err(err);
-            };
-            Err(GdalError::InvalidCoordinateRange {
-                from: self.from.clone(),
-                to: self.to.clone(),
-                msg,
-            })
+                Some(msg)
+            }
+        } else {
+            return err;
+        };
+        GdalError::InvalidCoordinateRange {
+            from: self.from.clone(),
+            to: self.to.clone(),
+            msg,
         }
     }
 
@@ -289,6 +449,47 @@ mod tests {
         assert_almost_eq(zs[0], 32.0);
     }
 
+    #[test]
+    fn transform_coordinates_with_time() {
+        // ITRF2014 to NAD83(CSRS), both geocentric, is a time-dependent Helmert transformation
+        // following the North American plate motion. The NRCan parameters are pinned so that
+        // the reference values don't depend on the PROJ database.
+        let itrf2014 = SpatialRef::from_epsg(7789).unwrap();
+        let nad83_csrs = SpatialRef::from_epsg(4954).unwrap();
+        let mut options = crate::spatial_ref::CoordTransformOptions::new().unwrap();
+        options
+            .set_coordinate_operation(
+                "+proj=helmert +x=1.0053 +y=-1.9092 +z=-0.5416 \
+                 +rx=-0.0267814 +ry=0.0004203 +rz=-0.0109321 +s=0.00037 \
+                 +dx=0.0008 +dy=-0.0006 +dz=-0.0014 \
+                 +drx=-0.0000667 +dry=0.0007515 +drz=0.0000513 +ds=-0.00007 \
+                 +t_epoch=2010 +convention=coordinate_frame",
+                false,
+            )
+            .unwrap();
+
+        let transform = CoordTransform::new_with_options(&itrf2014, &nad83_csrs, &options).unwrap();
+        let mut xs = [1_106_000.0, 1_106_000.0];
+        let mut ys = [-4_344_000.0, -4_344_000.0];
+        let mut zs = [4_518_000.0, 4_518_000.0];
+        let mut ts = [2010.0, 2020.0];
+        transform
+            .transform_coords_with_time(&mut xs, &mut ys, &mut zs, &mut ts)
+            .unwrap();
+
+        // the same input point moves by about 17 cm between the two epochs
+        let expected = [
+            (1106001.2267, -4344002.4388, 4517998.8983),
+            (1106001.0586, -4344002.4591, 4517998.9074),
+        ];
+        for (i, (x, y, z)) in expected.into_iter().enumerate() {
+            assert!((xs[i] - x).abs() < 1e-3, "{} != {x}", xs[i]);
+            assert!((ys[i] - y).abs() < 1e-3, "{} != {y}", ys[i]);
+            assert!((zs[i] - z).abs() < 1e-3, "{} != {z}", zs[i]);
+        }
+        assert_eq!(ts, [2010.0, 2020.0]);
+    }
+
     #[test]
     fn transform_ogr_geometry() {
         let mut geom = Geometry::from_wkt(
@@ -321,6 +522,35 @@ mod tests {
         assert_eq!(points[4], points[0]);
     }
 
+    #[test]
+    fn transform_coordinates_with_mask() {
+        let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        let mut webmercator = SpatialRef::from_epsg(3857).unwrap();
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        webmercator.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        let trafo = CoordTransform::new(&wgs84, &webmercator).unwrap();
+
+        let mut x = [0.0, 1000000.0, 10.0];
+        let mut y = [0.0, 1000000.0, 45.0];
+        let mask = trafo.transform_coords_with_mask(&mut x, &mut y, &mut [], &mut []);
+        assert_eq!(mask, [true, false, true]);
+        assert_almost_eq(x[0], 0.0);
+        assert_almost_eq(x[2], 1113194.9079327357);
+        assert_almost_eq(y[2], 5621521.486192066);
+
+        let mut points = [
+            geo_types::coord! { x: 0.0, y: 0.0 },
+            geo_types::coord! { x: 1000000.0, y: 1000000.0 },
+            geo_types::coord! { x: 10.0, y: 45.0 },
+        ];
+        let mask = trafo.transform_points(&mut points);
+        assert_eq!(mask, [true, false, true]);
+        // failed points are left untouched
+        assert_eq!(points[1], geo_types::coord! { x: 1000000.0, y: 1000000.0 });
+        assert_almost_eq(points[2].x, 1113194.9079327357);
+        assert_almost_eq(points[2].y, 5621521.486192066);
+    }
+
     #[test]
     fn failing_transformation() {
         let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
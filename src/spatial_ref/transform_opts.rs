@@ -120,6 +120,28 @@ impl CoordTransformOptions {
         Ok(())
     }
 
+    /// Sets whether only the best coordinate operation may be used.
+    ///
+    /// PROJ may know several candidate operations between two CRSs, ranked by accuracy and
+    /// area of use. By default, when the best candidate cannot be instantiated (typically
+    /// because a required grid file is not available), a less accurate one is silently used
+    /// instead, possibly down to a ballpark transformation. When `only_best` is `true`,
+    /// [`CoordTransform::new_with_options`] will fail instead.
+    ///
+    /// The same behavior can be obtained globally with the `OSR_CT_ONLY_BEST` configuration
+    /// option.
+    ///
+    /// See: [`OCTCoordinateTransformationOptionsSetOnlyBest`](https://gdal.org/api/ogr_srs_api.html#_CPPv445OCTCoordinateTransformationOptionsSetOnlyBest35OGRCoordinateTransformationOptionsHb)
+    pub fn set_only_best(&mut self, only_best: bool) -> Result<()> {
+        let ret_val = unsafe {
+            gdal_sys::OCTCoordinateTransformationOptionsSetOnlyBest(self.inner, only_best)
+        };
+        if ret_val == 0 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(())
+    }
+
     /// Sets a coordinate operation.
     ///
     /// This is a user override to be used instead of the normally computed pipeline.
@@ -174,6 +196,32 @@ mod tests {
         assert!(matches!(err, GdalError::NullPointer { .. }), "{err:?}");
     }
 
+    #[test]
+    fn only_best() {
+        let nad27 = SpatialRef::from_epsg(4267).unwrap();
+        let wgs84 = SpatialRef::from_epsg(4326).unwrap();
+
+        let mut options = CoordTransformOptions::new().unwrap();
+        options.set_only_best(false).unwrap();
+        assert!(CoordTransform::new_with_options(&nad27, &wgs84, &options).is_ok());
+
+        // a conversion without grids is always the best operation
+        options.set_only_best(true).unwrap();
+        let web_mercator = SpatialRef::from_epsg(3857).unwrap();
+        assert!(CoordTransform::new_with_options(&wgs84, &web_mercator, &options).is_ok());
+
+        // the best NAD27 to WGS 84 operation needs the NADCON grids, so whether this succeeds
+        // depends on their availability
+        #[cfg(feature = "proj")]
+        {
+            let operations = crate::spatial_ref::coordinate_operations(&nad27, &wgs84).unwrap();
+            assert_eq!(
+                CoordTransform::new_with_options(&nad27, &wgs84, &options).is_ok(),
+                operations[0].is_instantiable
+            );
+        }
+    }
+
     #[test]
     fn set_coordinate_operation() {
         // Test case taken from:
@@ -1,7 +1,8 @@
-use std::ffi::{c_char, CStr, CString};
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::panic::AssertUnwindSafe;
 use std::path::{Path, PathBuf};
 
-use gdal_sys::CPLErr;
+use gdal_sys::{CPLErr, GDALProgressFunc};
 
 use crate::errors::*;
 
@@ -86,3 +87,31 @@ pub fn _path_to_c_string(path: &Path) -> Result<CString> {
     let path_str = path.to_string_lossy();
     CString::new(path_str.as_ref()).map_err(Into::into)
 }
+
+/// Returns the `pfnProgress` and `pProgressData` arguments reporting the progress of a GDAL
+/// operation to `progress`, which is called with the completed fraction and returns `false`
+/// to cancel the operation.
+///
+/// The returned pointer borrows `progress`, which must outlive the operation.
+pub fn _progress_args(
+    progress: &mut Option<&mut dyn FnMut(f64) -> bool>,
+) -> (GDALProgressFunc, *mut c_void) {
+    unsafe extern "C" fn trampoline(
+        complete: f64,
+        _message: *const c_char,
+        data: *mut c_void,
+    ) -> c_int {
+        let progress = unsafe { &mut *(data as *mut &mut dyn FnMut(f64) -> bool) };
+        // Unwinding into GDAL is undefined behaviour, so a panic cancels the operation.
+        let proceed = std::panic::catch_unwind(AssertUnwindSafe(|| progress(complete)));
+        c_int::from(proceed.unwrap_or(false))
+    }
+
+    match progress {
+        Some(progress) => (
+            Some(trampoline),
+            progress as *mut &mut dyn FnMut(f64) -> bool as *mut c_void,
+        ),
+        None => (None, std::ptr::null_mut()),
+    }
+}
@@ -6,6 +6,8 @@ use gdal_sys::{
 
 use crate::spatial_ref::SpatialRef;
 use crate::utils::{_last_null_pointer_err, _string};
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+use crate::vector::CoordinatePrecision;
 use crate::vector::LayerAccess;
 
 use crate::errors::*;
@@ -248,4 +250,17 @@ impl<'a> GeomField<'a> {
         }
         unsafe { SpatialRef::from_c_obj(c_obj) }
     }
+
+    /// Get the resolution of the coordinates of this field, as set when creating the layer
+    /// with [`LayerOptions::coordinate_precision`][crate::vector::LayerOptions::coordinate_precision]
+    /// or read from the dataset. Unknown resolutions are `0`.
+    ///
+    /// See: [`OGR_GFld_GetCoordinatePrecision`](https://gdal.org/api/vector_c_api.html#_CPPv431OGR_GFld_GetCoordinatePrecision17OGRGeomFieldDefnH)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    pub fn coordinate_precision(&'a self) -> CoordinatePrecision {
+        unsafe {
+            let c_precision = gdal_sys::OGR_GFld_GetCoordinatePrecision(self.c_field_defn);
+            CoordinatePrecision::from_c_precision(c_precision)
+        }
+    }
 }
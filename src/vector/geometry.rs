@@ -1,17 +1,19 @@
 use std::{
     cell::RefCell,
-    ffi::{c_double, c_int},
+    ffi::{c_double, c_int, c_void},
     fmt::{self, Debug, Formatter},
     marker::PhantomData,
     mem::MaybeUninit,
     ops::{Deref, DerefMut},
+    ptr,
 };
 
-use gdal_sys::{OGRErr, OGRGeometryH, OGRwkbGeometryType};
+use gdal_sys::{CPLErr, OGRErr, OGRGeometryH, OGRwkbGeometryType};
 
+use crate::cpl::CslStringList;
 use crate::errors::*;
 use crate::spatial_ref::SpatialRef;
-use crate::utils::{_last_null_pointer_err, _string};
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
 use crate::vector::{Envelope, Envelope3D};
 
 /// OGR Geometry
@@ -83,6 +85,52 @@ impl Geometry {
         ))
     }
 
+    /// Create a `CircularString` from its control points.
+    ///
+    /// A circular string is made of one or more circular arcs, each defined by a start point,
+    /// an intermediate point and an end point; consecutive arcs share their end and start points.
+    pub fn circular_string(points: &[(f64, f64)]) -> Result<Geometry> {
+        let mut geom = Geometry::empty(OGRwkbGeometryType::wkbCircularString)?;
+        for &point in points {
+            geom.add_point_2d(point);
+        }
+        Ok(geom)
+    }
+
+    /// Create a `CompoundCurve` from a sequence of contiguous `LineString`s and
+    /// `CircularString`s.
+    pub fn compound_curve(curves: impl IntoIterator<Item = Geometry>) -> Result<Geometry> {
+        Geometry::collect(OGRwkbGeometryType::wkbCompoundCurve, curves)
+    }
+
+    /// Create a `CurvePolygon` from its rings: an exterior ring followed by interior rings.
+    ///
+    /// Rings may be closed `LineString`s, `CircularString`s or `CompoundCurve`s.
+    pub fn curve_polygon(rings: impl IntoIterator<Item = Geometry>) -> Result<Geometry> {
+        Geometry::collect(OGRwkbGeometryType::wkbCurvePolygon, rings)
+    }
+
+    /// Create a `MultiCurve` from `LineString`s, `CircularString`s or `CompoundCurve`s.
+    pub fn multi_curve(curves: impl IntoIterator<Item = Geometry>) -> Result<Geometry> {
+        Geometry::collect(OGRwkbGeometryType::wkbMultiCurve, curves)
+    }
+
+    /// Create a `MultiSurface` from `Polygon`s or `CurvePolygon`s.
+    pub fn multi_surface(surfaces: impl IntoIterator<Item = Geometry>) -> Result<Geometry> {
+        Geometry::collect(OGRwkbGeometryType::wkbMultiSurface, surfaces)
+    }
+
+    fn collect(
+        wkb_type: OGRwkbGeometryType::Type,
+        parts: impl IntoIterator<Item = Geometry>,
+    ) -> Result<Geometry> {
+        let mut geom = Geometry::empty(wkb_type)?;
+        for part in parts {
+            geom.add_geometry(part)?;
+        }
+        Ok(geom)
+    }
+
     /// Returns a C pointer to the wrapped Geometry
     ///
     /// # Safety
@@ -223,22 +271,222 @@ impl Geometry {
     ///
     /// For some geometry types, like polygons, that don't consist of points, `out_points` will not be modified.
     pub fn get_points(&self, out_points: &mut Vec<(f64, f64, f64)>) -> usize {
-        // Consider replacing logic with
-        // [OGR_G_GetPoints](https://gdal.org/en/stable/api/vector_c_api.html#_CPPv415OGR_G_GetPoints12OGRGeometryHPviPviPvi)
-        let length = unsafe { gdal_sys::OGR_G_GetPointCount(self.c_geometry()) };
-        out_points.extend((0..length).map(|i| self.get_point(i)));
-        length as usize
+        let mut coords = vec![0.; self.point_count() * 3];
+        let Ok(length) = self.read_points_interleaved(&mut coords, CoordinateLayout::Xyz) else {
+            return 0;
+        };
+        out_points.extend(coords.chunks_exact(3).map(|c| (c[0], c[1], c[2])));
+        length
     }
 
     /// Appends all points in the geometry to `out_points`, as XYZM.
     ///
     /// For some geometry types, like polygons, that don't consist of points, `out_points` will not be modified.
     pub fn get_points_zm(&self, out_points: &mut Vec<(f64, f64, f64, f64)>) -> usize {
-        // Consider replacing logic with
-        // [OGR_G_GetPoints](https://gdal.org/en/stable/api/vector_c_api.html#_CPPv415OGR_G_GetPoints12OGRGeometryHPviPviPvi)
-        let length = unsafe { gdal_sys::OGR_G_GetPointCount(self.c_geometry()) };
-        out_points.extend((0..length).map(|i| self.get_point_zm(i)));
-        length as usize
+        let mut coords = vec![0.; self.point_count() * 4];
+        let Ok(length) = self.read_points_interleaved(&mut coords, CoordinateLayout::Xyzm) else {
+            return 0;
+        };
+        out_points.extend(coords.chunks_exact(4).map(|c| (c[0], c[1], c[2], c[3])));
+        length
+    }
+
+    /// Copies the coordinates of a line string or point geometry into the given slices,
+    /// without intermediate allocations.
+    ///
+    /// Each slice must hold at least [`point_count`][Self::point_count] values. Pass `None` for
+    /// `z` or `m` to skip that dimension; missing dimensions of the geometry are read as `0`.
+    ///
+    /// Returns the number of points copied, which is `0` for geometries that don't consist
+    /// of points, like polygons.
+    ///
+    /// See: [`OGR_G_GetPointsZM`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_GetPointsZM12OGRGeometryHPviPviPviPvi)
+    pub fn read_points(
+        &self,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: Option<&mut [f64]>,
+        m: Option<&mut [f64]>,
+    ) -> Result<usize> {
+        let n = self.point_count();
+        let too_short = x.len() < n
+            || y.len() < n
+            || z.as_ref().is_some_and(|z| z.len() < n)
+            || m.as_ref().is_some_and(|m| m.len() < n);
+        if too_short {
+            return Err(GdalError::BadArgument(format!(
+                "coordinate slices must hold at least {n} values"
+            )));
+        }
+        let stride = size_of::<f64>() as c_int;
+        let z = z.map_or(ptr::null_mut(), |z| z.as_mut_ptr());
+        let m = m.map_or(ptr::null_mut(), |m| m.as_mut_ptr());
+        unsafe { self.read_points_raw(n, [x.as_mut_ptr(), y.as_mut_ptr(), z, m], [stride; 4]) }
+    }
+
+    /// Copies the coordinates of a line string or point geometry into `coords`, with the
+    /// dimensions of each point interleaved as described by `layout`, e.g. `[x0, y0, z0, x1, ...]`
+    /// for [`CoordinateLayout::Xyz`].
+    ///
+    /// `coords` must hold at least `point_count() * layout.dimension()` values; missing
+    /// dimensions of the geometry are read as `0`.
+    ///
+    /// Returns the number of points copied, which is `0` for geometries that don't consist
+    /// of points, like polygons.
+    ///
+    /// See: [`OGR_G_GetPointsZM`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_GetPointsZM12OGRGeometryHPviPviPviPvi)
+    pub fn read_points_interleaved(
+        &self,
+        coords: &mut [f64],
+        layout: CoordinateLayout,
+    ) -> Result<usize> {
+        let n = self.point_count();
+        let dimension = layout.dimension();
+        if coords.len() < n * dimension {
+            return Err(GdalError::BadArgument(format!(
+                "coordinate slice must hold at least {} values",
+                n * dimension
+            )));
+        }
+        let stride = (dimension * size_of::<f64>()) as c_int;
+        let base = coords.as_mut_ptr();
+        let [x, y, z, m] = layout.offsets().map(|offset| match offset {
+            Some(offset) => unsafe { base.add(offset) },
+            None => ptr::null_mut(),
+        });
+        unsafe { self.read_points_raw(n, [x, y, z, m], [stride; 4]) }
+    }
+
+    /// # Safety
+    /// Each non-null pointer must be valid for writing `n` values with the given byte stride.
+    unsafe fn read_points_raw(
+        &self,
+        n: usize,
+        [x, y, z, m]: [*mut f64; 4],
+        [x_stride, y_stride, z_stride, m_stride]: [c_int; 4],
+    ) -> Result<usize> {
+        if n == 0 {
+            return Ok(0);
+        }
+        let rv = gdal_sys::OGR_G_GetPointsZM(
+            self.c_geometry(),
+            x as *mut c_void,
+            x_stride,
+            y as *mut c_void,
+            y_stride,
+            z as *mut c_void,
+            z_stride,
+            m as *mut c_void,
+            m_stride,
+        );
+        if rv == 0 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(rv as usize)
+    }
+
+    /// Replaces all the points of a line string (or the single point of a point geometry)
+    /// with the given coordinates, in a single call.
+    ///
+    /// All the slices must have the same length, which must be 1 for a point geometry. The
+    /// geometry becomes 3D if `z` is given, and measured if `m` is given; otherwise that
+    /// dimension is dropped.
+    ///
+    /// See: [`OGR_G_SetPointsZM`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_SetPointsZM12OGRGeometryHiPKviPKviPKviPKvi)
+    pub fn set_points(
+        &mut self,
+        x: &[f64],
+        y: &[f64],
+        z: Option<&[f64]>,
+        m: Option<&[f64]>,
+    ) -> Result<()> {
+        let n = x.len();
+        let mismatch =
+            y.len() != n || z.is_some_and(|z| z.len() != n) || m.is_some_and(|m| m.len() != n);
+        if mismatch {
+            return Err(GdalError::BadArgument(
+                "coordinate slices must all have the same length".to_string(),
+            ));
+        }
+        let stride = size_of::<f64>() as c_int;
+        let z = z.map_or(ptr::null(), |z| z.as_ptr());
+        let m = m.map_or(ptr::null(), |m| m.as_ptr());
+        unsafe { self.set_points_raw(n, [x.as_ptr(), y.as_ptr(), z, m], stride) }
+    }
+
+    /// Replaces all the points of a line string (or the single point of a point geometry)
+    /// with the coordinates of `coords`, interleaved as described by `layout`.
+    ///
+    /// The length of `coords` must be a multiple of `layout.dimension()`, and hold a single
+    /// coordinate for a point geometry. The geometry becomes 3D and/or measured according to
+    /// `layout`.
+    ///
+    /// See: [`OGR_G_SetPointsZM`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_SetPointsZM12OGRGeometryHiPKviPKviPKviPKvi)
+    pub fn set_points_interleaved(
+        &mut self,
+        coords: &[f64],
+        layout: CoordinateLayout,
+    ) -> Result<()> {
+        let dimension = layout.dimension();
+        if coords.len() % dimension != 0 {
+            return Err(GdalError::BadArgument(format!(
+                "coordinate slice length must be a multiple of {dimension}"
+            )));
+        }
+        let stride = (dimension * size_of::<f64>()) as c_int;
+        let base = coords.as_ptr();
+        let [x, y, z, m] = layout.offsets().map(|offset| match offset {
+            Some(offset) => unsafe { base.add(offset) },
+            None => ptr::null(),
+        });
+        unsafe { self.set_points_raw(coords.len() / dimension, [x, y, z, m], stride) }
+    }
+
+    /// Drops the Z and M dimensions of the geometry when `z` and `m` are null.
+    ///
+    /// # Safety
+    /// Each non-null pointer must be valid for reading `n` values with the given byte stride.
+    unsafe fn set_points_raw(
+        &mut self,
+        n: usize,
+        [x, y, z, m]: [*const f64; 4],
+        stride: c_int,
+    ) -> Result<()> {
+        // `OGR_G_SetPointsZM` reads the first coordinate of a point without checking `n`
+        if geometry_type_flatten(self.geometry_type()) == OGRwkbGeometryType::wkbPoint && n != 1 {
+            return Err(GdalError::BadArgument(format!(
+                "a point geometry needs exactly 1 coordinate, got {n}"
+            )));
+        }
+        let n: c_int = n.try_into()?;
+        // `OGR_G_SetPointsZM` only replaces the Z and M values when the stride of a null
+        // pointer is 0; otherwise it keeps the existing ones
+        let [x_stride, y_stride, z_stride, m_stride] =
+            [x, y, z, m].map(|ptr| if ptr.is_null() { 0 } else { stride });
+        gdal_sys::CPLErrorReset();
+        gdal_sys::OGR_G_SetPointsZM(
+            self.c_geometry(),
+            n,
+            x as *const c_void,
+            x_stride,
+            y as *const c_void,
+            y_stride,
+            z as *const c_void,
+            z_stride,
+            m as *const c_void,
+            m_stride,
+        );
+        let cpl_err = gdal_sys::CPLGetLastErrorType();
+        if cpl_err != CPLErr::CE_None {
+            return Err(_last_cpl_err(cpl_err));
+        }
+        if z.is_null() {
+            self.set_3d(false);
+        }
+        if m.is_null() {
+            self.set_measured(false);
+        }
+        Ok(())
     }
 
     /// Get the geometry type ordinal
@@ -260,8 +508,9 @@ impl Geometry {
 
     /// Get the number of elements in a geometry, or number of geometries in container.
     ///
-    /// Only geometries of type `wkbPolygon`, `wkbMultiPoint`, `wkbMultiLineString`, `wkbMultiPolygon`
-    /// or `wkbGeometryCollection` may return a non-zero value. Other geometry types will return 0.
+    /// Only geometries of type `wkbPolygon`, `wkbMultiPoint`, `wkbMultiLineString`, `wkbMultiPolygon`,
+    /// `wkbGeometryCollection`, `wkbCompoundCurve`, `wkbCurvePolygon`, `wkbMultiCurve`
+    /// or `wkbMultiSurface` may return a non-zero value. Other geometry types will return 0.
     ///
     /// For a polygon, the returned number is the number of rings (exterior ring + interior rings).
     ///
@@ -271,9 +520,10 @@ impl Geometry {
         cnt as usize
     }
 
-    /// Get the number of points from a Point or a LineString/LinearRing geometry.
+    /// Get the number of points from a Point or a LineString/LinearRing/CircularString geometry.
     ///
-    /// Only `wkbPoint` or `wkbLineString` may return a non-zero value. Other geometry types will return 0.
+    /// Only `wkbPoint`, `wkbLineString` or `wkbCircularString` may return a non-zero value.
+    /// Other geometry types will return 0.
     ///
     /// See: [`OGR_G_GetPointCount`](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_GetPointCount12OGRGeometryH)
     pub fn point_count(&self) -> usize {
@@ -335,6 +585,36 @@ impl Geometry {
         unsafe { gdal_sys::OGR_G_Area(self.c_geometry()) }
     }
 
+    /// Compute the 2D distance between this geometry and `other`.
+    ///
+    /// # Notes
+    /// If GEOS is *not* enabled, this function will always return `None`.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// Returns `None` on error, e.g. if one of the geometries is empty.
+    ///
+    /// See: [`OGR_G_Distance`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_Distance12OGRGeometryH12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn distance(&self, other: &Self) -> Option<f64> {
+        let d = unsafe { gdal_sys::OGR_G_Distance(self.c_geometry(), other.c_geometry()) };
+        (d >= 0.0).then_some(d)
+    }
+
+    /// Compute the 3D distance between this geometry and `other`.
+    ///
+    /// # Notes
+    /// This function requires the SFCGAL library.
+    /// If OGR is built without the SFCGAL library, this function will always return `None`.
+    ///
+    /// Returns `None` on error, e.g. if one of the geometries is empty.
+    ///
+    /// See: [`OGR_G_Distance3D`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_Distance3D12OGRGeometryH12OGRGeometryH)
+    pub fn distance_3d(&self, other: &Self) -> Option<f64> {
+        let d = unsafe { gdal_sys::OGR_G_Distance3D(self.c_geometry(), other.c_geometry()) };
+        (d >= 0.0).then_some(d)
+    }
+
     /// Computes and returns the axis-aligned 2D bounding envelope for this geometry.
     ///
     /// See: [`OGR_G_GetEnvelope`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_GetEnvelope12OGRGeometryHP11OGREnvelope)
@@ -364,6 +644,120 @@ impl Geometry {
         unsafe { gdal_sys::OGR_G_FlattenTo2D(self.c_geometry()) };
     }
 
+    /// Returns `true` if this geometry has Z coordinates.
+    ///
+    /// See: [`OGR_G_Is3D`](https://gdal.org/api/vector_c_api.html#_CPPv410OGR_G_Is3D12OGRGeometryH)
+    pub fn is_3d(&self) -> bool {
+        unsafe { gdal_sys::OGR_G_Is3D(self.c_geometry()) != 0 }
+    }
+
+    /// Returns `true` if this geometry has M coordinates.
+    ///
+    /// See: [`OGR_G_IsMeasured`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_IsMeasured12OGRGeometryH)
+    pub fn is_measured(&self) -> bool {
+        unsafe { gdal_sys::OGR_G_IsMeasured(self.c_geometry()) != 0 }
+    }
+
+    /// Adds or removes the Z coordinates of this geometry and of its parts.
+    ///
+    /// Added Z values are set to `0`.
+    ///
+    /// See: [`OGR_G_Set3D`](https://gdal.org/api/vector_c_api.html#_CPPv411OGR_G_Set3D12OGRGeometryHi)
+    pub fn set_3d(&mut self, is_3d: bool) {
+        unsafe { gdal_sys::OGR_G_Set3D(self.c_geometry(), is_3d as c_int) };
+    }
+
+    /// Adds or removes the M coordinates of this geometry and of its parts.
+    ///
+    /// Added M values are set to `0`.
+    ///
+    /// See: [`OGR_G_SetMeasured`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_SetMeasured12OGRGeometryHi)
+    pub fn set_measured(&mut self, is_measured: bool) {
+        unsafe { gdal_sys::OGR_G_SetMeasured(self.c_geometry(), is_measured as c_int) };
+    }
+
+    /// Converts this geometry to the `target` geometry type, consuming it.
+    ///
+    /// This handles all the "safe" conversions (e.g. `Polygon` to `MultiPolygon`, `MultiPoint`
+    /// of a single point to `Point`, or `CurvePolygon` to `Polygon`). If the conversion is not
+    /// possible, the geometry is returned unchanged.
+    ///
+    /// `opts` is currently unused by GDAL and should be empty.
+    ///
+    /// See: [`OGR_G_ForceTo`](https://gdal.org/api/vector_c_api.html#_CPPv413OGR_G_ForceTo12OGRGeometryH18OGRwkbGeometryTypePPc)
+    pub fn force_to(self, target: OGRwkbGeometryType::Type, opts: &CslStringList) -> Geometry {
+        let c_geom =
+            unsafe { gdal_sys::OGR_G_ForceTo(self.into_c_geometry(), target, opts.as_ptr()) };
+        unsafe { Geometry::with_c_geometry(c_geom, true) }
+    }
+
+    /// Converts this geometry to a `Polygon`, consuming it.
+    ///
+    /// `CurvePolygon`s are linearized, and `MultiPolygon`s or `MultiSurface`s are turned
+    /// into a single polygon by merging their rings. Other geometries are returned unchanged.
+    ///
+    /// See: [`OGR_G_ForceToPolygon`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_ForceToPolygon12OGRGeometryH)
+    pub fn force_to_polygon(self) -> Geometry {
+        let c_geom = unsafe { gdal_sys::OGR_G_ForceToPolygon(self.into_c_geometry()) };
+        unsafe { Geometry::with_c_geometry(c_geom, true) }
+    }
+
+    /// Converts this geometry to a `MultiPolygon`, consuming it.
+    ///
+    /// Geometries that cannot be converted are returned unchanged.
+    ///
+    /// See: [`OGR_G_ForceToMultiPolygon`](https://gdal.org/api/vector_c_api.html#_CPPv425OGR_G_ForceToMultiPolygon12OGRGeometryH)
+    pub fn force_to_multi_polygon(self) -> Geometry {
+        let c_geom = unsafe { gdal_sys::OGR_G_ForceToMultiPolygon(self.into_c_geometry()) };
+        unsafe { Geometry::with_c_geometry(c_geom, true) }
+    }
+
+    /// Converts this geometry to a `LineString`, consuming it.
+    ///
+    /// The parts of a `MultiLineString` are joined together when their endpoints match.
+    /// Geometries that cannot be converted are returned unchanged.
+    ///
+    /// See: [`OGR_G_ForceToLineString`](https://gdal.org/api/vector_c_api.html#_CPPv423OGR_G_ForceToLineString12OGRGeometryH)
+    pub fn force_to_line_string(self) -> Geometry {
+        let c_geom = unsafe { gdal_sys::OGR_G_ForceToLineString(self.into_c_geometry()) };
+        unsafe { Geometry::with_c_geometry(c_geom, true) }
+    }
+
+    /// Converts this geometry to a `MultiLineString`, consuming it.
+    ///
+    /// Geometries that cannot be converted are returned unchanged.
+    ///
+    /// See: [`OGR_G_ForceToMultiLineString`](https://gdal.org/api/vector_c_api.html#_CPPv428OGR_G_ForceToMultiLineString12OGRGeometryH)
+    pub fn force_to_multi_line_string(self) -> Geometry {
+        let c_geom = unsafe { gdal_sys::OGR_G_ForceToMultiLineString(self.into_c_geometry()) };
+        unsafe { Geometry::with_c_geometry(c_geom, true) }
+    }
+
+    /// Converts this geometry to a `MultiPoint`, consuming it.
+    ///
+    /// Geometries that cannot be converted are returned unchanged.
+    ///
+    /// See: [`OGR_G_ForceToMultiPoint`](https://gdal.org/api/vector_c_api.html#_CPPv423OGR_G_ForceToMultiPoint12OGRGeometryH)
+    pub fn force_to_multi_point(self) -> Geometry {
+        let c_geom = unsafe { gdal_sys::OGR_G_ForceToMultiPoint(self.into_c_geometry()) };
+        unsafe { Geometry::with_c_geometry(c_geom, true) }
+    }
+
+    /// Converts a single-part geometry to the corresponding multi-part geometry, consuming it.
+    ///
+    /// For instance, a `Polygon` becomes a `MultiPolygon` and a `LineStringZ` a
+    /// `MultiLineStringZ`. Collections and other geometries are returned unchanged.
+    ///
+    /// See: [`geometry_type_collection`]
+    pub fn force_to_multi(self) -> Geometry {
+        let ty = self.geometry_type();
+        let multi_ty = geometry_type_collection(ty);
+        if multi_ty == ty || geometry_type_flatten(multi_ty) == OGRwkbGeometryType::wkbUnknown {
+            return self;
+        }
+        self.force_to(multi_ty, &CslStringList::new())
+    }
+
     /// Get the spatial reference system for this geometry.
     ///
     /// Returns `Some(SpatialRef)`, or `None` if one isn't defined.
@@ -392,7 +786,7 @@ impl Geometry {
     /// If OGR is built without the GEOS library, this function will always return `false`.
     /// Check with [`VersionInfo::has_geos`][has_geos].
     ///
-    /// See: [`Self::make_valid`]
+    /// See: [`Self::make_valid`], [`Self::validity_reason`]
     /// See: [`OGR_G_IsValid`](https://gdal.org/api/vector_c_api.html#_CPPv413OGR_G_IsValid12OGRGeometryH)
     ///
     /// [has_geos]: crate::version::VersionInfo::has_geos
@@ -400,6 +794,157 @@ impl Geometry {
         let p = unsafe { gdal_sys::OGR_G_IsValid(self.c_geometry()) };
         p != 0
     }
+
+    /// Test if the geometry is simple, i.e. it has no anomalous geometric points such as
+    /// self-intersection or self-tangency.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// If OGR is built without the GEOS library, this function will always return `false`.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_IsSimple`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_IsSimple12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn is_simple(&self) -> bool {
+        let p = unsafe { gdal_sys::OGR_G_IsSimple(self.c_geometry()) };
+        p != 0
+    }
+
+    /// Test if the geometry is a ring, i.e. it is closed and simple.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// If OGR is built without the GEOS library, this function will always return `false`.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_IsRing`](https://gdal.org/api/vector_c_api.html#_CPPv412OGR_G_IsRing12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn is_ring(&self) -> bool {
+        let p = unsafe { gdal_sys::OGR_G_IsRing(self.c_geometry()) };
+        p != 0
+    }
+
+    /// Test if this geometry is or contains a curve geometry type (`CircularString`,
+    /// `CompoundCurve`, `CurvePolygon`, `MultiCurve` or `MultiSurface`).
+    ///
+    /// If `look_for_non_linear` is `true`, only return `true` if a curve geometry actually
+    /// contains a circular arc: e.g. a `CompoundCurve` made only of `LineString`s is linear.
+    ///
+    /// See: [`OGR_G_HasCurveGeometry`](https://gdal.org/api/vector_c_api.html#_CPPv422OGR_G_HasCurveGeometry12OGRGeometryHi)
+    pub fn has_curve_geometry(&self, look_for_non_linear: bool) -> bool {
+        let p = unsafe {
+            gdal_sys::OGR_G_HasCurveGeometry(self.c_geometry(), look_for_non_linear as c_int)
+        };
+        p != 0
+    }
+}
+
+/// Order of the dimensions of interleaved point coordinates.
+///
+/// See [`Geometry::read_points_interleaved`] and [`Geometry::set_points_interleaved`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CoordinateLayout {
+    /// `[x, y]`
+    Xy,
+    /// `[x, y, z]`
+    Xyz,
+    /// `[x, y, m]`
+    Xym,
+    /// `[x, y, z, m]`
+    Xyzm,
+}
+
+impl CoordinateLayout {
+    /// Number of values per point.
+    pub fn dimension(&self) -> usize {
+        match self {
+            Self::Xy => 2,
+            Self::Xyz | Self::Xym => 3,
+            Self::Xyzm => 4,
+        }
+    }
+
+    /// Offsets of the X, Y, Z and M values within a point, if present.
+    fn offsets(&self) -> [Option<usize>; 4] {
+        match self {
+            Self::Xy => [Some(0), Some(1), None, None],
+            Self::Xyz => [Some(0), Some(1), Some(2), None],
+            Self::Xym => [Some(0), Some(1), None, Some(2)],
+            Self::Xyzm => [Some(0), Some(1), Some(2), Some(3)],
+        }
+    }
+}
+
+/// Resolution of the coordinates of a geometry, i.e. the smallest difference between two
+/// coordinate values that is meaningful. A resolution of `0` means unknown.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CoordinatePrecision {
+    /// Resolution of the X and Y coordinates.
+    pub xy_resolution: f64,
+    /// Resolution of the Z coordinates.
+    pub z_resolution: f64,
+    /// Resolution of the M coordinates.
+    pub m_resolution: f64,
+}
+
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+impl CoordinatePrecision {
+    /// Returns the precision corresponding to resolutions expressed in meters for the X, Y
+    /// and Z coordinates of the `srs` CRS, e.g. converting them to degrees for a geographic
+    /// CRS. `m_resolution` is kept as is.
+    ///
+    /// See: [`OGRGeomCoordinatePrecisionSetFromMeter`](https://gdal.org/api/vector_c_api.html#_CPPv438OGRGeomCoordinatePrecisionSetFromMeter27OGRGeomCoordinatePrecisionH20OGRSpatialReferenceHddd)
+    pub fn from_meter(
+        srs: &SpatialRef,
+        xy_meter_resolution: f64,
+        z_meter_resolution: f64,
+        m_resolution: f64,
+    ) -> Self {
+        unsafe {
+            let c_precision = gdal_sys::OGRGeomCoordinatePrecisionCreate();
+            gdal_sys::OGRGeomCoordinatePrecisionSetFromMeter(
+                c_precision,
+                srs.to_c_hsrs(),
+                xy_meter_resolution,
+                z_meter_resolution,
+                m_resolution,
+            );
+            let precision = Self::from_c_precision(c_precision);
+            gdal_sys::OGRGeomCoordinatePrecisionDestroy(c_precision);
+            precision
+        }
+    }
+
+    /// Reads the resolutions of a `OGRGeomCoordinatePrecisionH`.
+    ///
+    /// # Safety
+    /// `c_precision` must be a valid handle.
+    pub(crate) unsafe fn from_c_precision(
+        c_precision: gdal_sys::OGRGeomCoordinatePrecisionH,
+    ) -> Self {
+        Self {
+            xy_resolution: gdal_sys::OGRGeomCoordinatePrecisionGetXYResolution(c_precision),
+            z_resolution: gdal_sys::OGRGeomCoordinatePrecisionGetZResolution(c_precision),
+            m_resolution: gdal_sys::OGRGeomCoordinatePrecisionGetMResolution(c_precision),
+        }
+    }
+
+    /// Creates a new `OGRGeomCoordinatePrecisionH`, which must be freed with
+    /// `OGRGeomCoordinatePrecisionDestroy`.
+    pub(crate) fn to_c_precision(self) -> gdal_sys::OGRGeomCoordinatePrecisionH {
+        unsafe {
+            let c_precision = gdal_sys::OGRGeomCoordinatePrecisionCreate();
+            gdal_sys::OGRGeomCoordinatePrecisionSet(
+                c_precision,
+                self.xy_resolution,
+                self.z_resolution,
+                self.m_resolution,
+            );
+            c_precision
+        }
+    }
 }
 
 impl Drop for Geometry {
@@ -459,6 +1004,32 @@ pub fn geometry_type_set_m(ty: OGRwkbGeometryType::Type) -> OGRwkbGeometryType::
     unsafe { gdal_sys::OGR_GT_SetM(ty) }
 }
 
+/// Returns `true` if the geometry type is a non-linear geometry type, i.e. one of
+/// `CircularString`, `CompoundCurve`, `CurvePolygon`, `MultiCurve` or `MultiSurface`.
+pub fn geometry_type_is_non_linear(ty: OGRwkbGeometryType::Type) -> bool {
+    unsafe { gdal_sys::OGR_GT_IsNonLinear(ty) != 0 }
+}
+
+/// Returns the linear geometry type corresponding to the passed geometry type,
+/// e.g. `LineString` for `CircularString`, or `Polygon` for `CurvePolygon`.
+pub fn geometry_type_linear(ty: OGRwkbGeometryType::Type) -> OGRwkbGeometryType::Type {
+    unsafe { gdal_sys::OGR_GT_GetLinear(ty) }
+}
+
+/// Returns the curve geometry type corresponding to the passed geometry type,
+/// e.g. `CompoundCurve` for `LineString`, or `CurvePolygon` for `Polygon`.
+pub fn geometry_type_curve(ty: OGRwkbGeometryType::Type) -> OGRwkbGeometryType::Type {
+    unsafe { gdal_sys::OGR_GT_GetCurve(ty) }
+}
+
+/// Returns the collection geometry type corresponding to the passed geometry type,
+/// e.g. `MultiPolygon` for `Polygon`, or `MultiCurve` for `CircularString`.
+///
+/// Returns `wkbUnknown` for types without a corresponding collection type.
+pub fn geometry_type_collection(ty: OGRwkbGeometryType::Type) -> OGRwkbGeometryType::Type {
+    unsafe { gdal_sys::OGR_GT_GetCollection(ty) }
+}
+
 /// Returns a XY, XYZ, XYM or XYZM geometry type depending on parameter.
 pub fn geometry_type_set_modifier(
     ty: OGRwkbGeometryType::Type,
@@ -512,7 +1083,10 @@ mod tests {
     use crate::spatial_ref::SpatialRef;
     use crate::test_utils::SuppressGDALErrorLog;
     use gdal_sys::OGRwkbGeometryType::{
-        wkbLineString, wkbLinearRing, wkbMultiPoint, wkbMultiPolygon, wkbPoint, wkbPolygon,
+        wkbCircularString, wkbCompoundCurve, wkbCurvePolygon, wkbLineString, wkbLineString25D,
+        wkbLineStringM, wkbLinearRing, wkbMultiCurve, wkbMultiLineString, wkbMultiLineStringM,
+        wkbMultiPoint, wkbMultiPoint25D, wkbMultiPolygon, wkbMultiSurface, wkbPoint, wkbPoint25D,
+        wkbPolygon,
     };
 
     #[test]
@@ -551,6 +1125,253 @@ mod tests {
         assert_eq!(geom.area().floor(), 25.0);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    pub fn test_distance() {
+        let point = Geometry::from_wkt("POINT (0 0)").unwrap();
+        let line = Geometry::from_wkt("LINESTRING (3 -10, 3 10)").unwrap();
+        assert_eq!(point.distance(&line), Some(3.0));
+
+        let _nolog = SuppressGDALErrorLog::new();
+        let empty = Geometry::empty(wkbPoint).unwrap();
+        assert_eq!(point.distance(&empty), None);
+    }
+
+    #[test]
+    pub fn test_is_simple_and_is_ring() {
+        let line = Geometry::from_wkt("LINESTRING (0 0, 10 0, 10 10)").unwrap();
+        assert!(line.is_simple());
+        assert!(!line.is_ring());
+
+        let ring = Geometry::from_wkt("LINESTRING (0 0, 10 0, 10 10, 0 0)").unwrap();
+        assert!(ring.is_simple());
+        assert!(ring.is_ring());
+
+        let bowtie = Geometry::from_wkt("LINESTRING (0 0, 10 10, 10 0, 0 10, 0 0)").unwrap();
+        assert!(!bowtie.is_simple());
+        assert!(!bowtie.is_ring());
+    }
+
+    #[test]
+    pub fn test_create_curves() {
+        let arc = Geometry::circular_string(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]).unwrap();
+        assert_eq!(arc.geometry_type(), wkbCircularString);
+        assert_eq!(arc.point_count(), 3);
+        assert_eq!(arc.get_point(1), (1.0, 1.0, 0.0));
+        assert!(arc.has_curve_geometry(true));
+
+        let mut line = Geometry::empty(wkbLineString).unwrap();
+        line.add_point_2d((2.0, 0.0));
+        line.add_point_2d((0.0, 0.0));
+        let ring = Geometry::compound_curve([arc, line]).unwrap();
+        assert_eq!(ring.geometry_type(), wkbCompoundCurve);
+        assert_eq!(ring.geometry_count(), 2);
+        assert_eq!(ring.get_geometry(0).geometry_type(), wkbCircularString);
+        assert_eq!(
+            ring.wkt().unwrap(),
+            "COMPOUNDCURVE (CIRCULARSTRING (0 0,1 1,2 0),(2 0,0 0))"
+        );
+
+        let polygon = Geometry::curve_polygon([ring]).unwrap();
+        assert_eq!(polygon.geometry_type(), wkbCurvePolygon);
+        // a half disc of radius 1
+        assert!((polygon.area() - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+
+        let multi_surface = Geometry::multi_surface([polygon]).unwrap();
+        assert_eq!(multi_surface.geometry_type(), wkbMultiSurface);
+        assert_eq!(multi_surface.geometry_count(), 1);
+
+        let mut line = Geometry::empty(wkbLineString).unwrap();
+        line.add_point_2d((0.0, 0.0));
+        line.add_point_2d((1.0, 0.0));
+        let multi_curve = Geometry::multi_curve([line]).unwrap();
+        assert_eq!(multi_curve.geometry_type(), wkbMultiCurve);
+        assert!(multi_curve.has_curve_geometry(false));
+        assert!(!multi_curve.has_curve_geometry(true));
+
+        let _nolog = SuppressGDALErrorLog::new();
+        let point = Geometry::from_wkt("POINT (0 0)").unwrap();
+        assert!(Geometry::multi_curve([point]).is_err());
+    }
+
+    #[test]
+    pub fn test_geometry_type_curves() {
+        assert!(geometry_type_is_non_linear(wkbCircularString));
+        assert!(!geometry_type_is_non_linear(wkbLineString));
+        assert_eq!(geometry_type_linear(wkbCurvePolygon), wkbPolygon);
+        assert_eq!(geometry_type_linear(wkbMultiSurface), wkbMultiPolygon);
+        assert_eq!(geometry_type_curve(wkbLineString), wkbCompoundCurve);
+        assert_eq!(geometry_type_curve(wkbMultiPolygon), wkbMultiSurface);
+    }
+
+    #[test]
+    pub fn test_set_3d_and_measured() {
+        let mut line = Geometry::from_wkt("LINESTRING (0 0,1 1)").unwrap();
+        assert!(!line.is_3d() && !line.is_measured());
+        line.set_3d(true);
+        line.set_measured(true);
+        assert!(line.is_3d() && line.is_measured());
+        assert_eq!(line.wkt().unwrap(), "LINESTRING ZM (0 0 0 0,1 1 0 0)");
+        line.set_3d(false);
+        assert_eq!(line.wkt().unwrap(), "LINESTRING M (0 0 0,1 1 0)");
+    }
+
+    #[test]
+    pub fn test_force_to() {
+        let polygon = Geometry::from_wkt("POLYGON ((0 0,0 1,1 1,0 0))").unwrap();
+        let multi = polygon.clone().force_to_multi_polygon();
+        assert_eq!(multi.geometry_type(), wkbMultiPolygon);
+        assert_eq!(multi.geometry_count(), 1);
+        let back = multi.force_to_polygon();
+        assert_eq!(back, polygon);
+
+        let lines = Geometry::from_wkt("MULTILINESTRING ((0 0,1 1),(1 1,2 0))").unwrap();
+        let line = lines.force_to_line_string();
+        assert_eq!(line.geometry_type(), wkbLineString);
+        assert_eq!(line.point_count(), 3);
+        assert_eq!(
+            line.force_to_multi_line_string().geometry_type(),
+            wkbMultiLineString
+        );
+
+        let point = Geometry::from_wkt("POINT Z (1 2 3)").unwrap();
+        let multi = point.clone().force_to_multi_point();
+        assert_eq!(multi.geometry_type(), wkbMultiPoint25D);
+        assert_eq!(multi.force_to(wkbPoint25D, &CslStringList::new()), point);
+
+        // impossible conversions leave the geometry unchanged
+        let point = Geometry::from_wkt("POINT (1 2)").unwrap();
+        assert_eq!(point.clone().force_to_polygon(), point);
+    }
+
+    #[test]
+    pub fn test_force_to_multi() {
+        let curve = Geometry::from_wkt("CIRCULARSTRING (0 0,1 1,2 0)").unwrap();
+        assert_eq!(curve.force_to_multi().geometry_type(), wkbMultiCurve);
+        let line = Geometry::from_wkt("LINESTRING M (0 0 1,1 1 2)").unwrap();
+        assert_eq!(line.force_to_multi().geometry_type(), wkbMultiLineStringM);
+        let multi = Geometry::from_wkt("MULTIPOINT ((0 0))").unwrap();
+        assert_eq!(multi.clone().force_to_multi(), multi);
+        let collection = Geometry::from_wkt("GEOMETRYCOLLECTION (POINT (0 0))").unwrap();
+        assert_eq!(collection.clone().force_to_multi(), collection);
+
+        assert_eq!(geometry_type_collection(wkbPolygon), wkbMultiPolygon);
+        assert_eq!(geometry_type_collection(wkbMultiPolygon), wkbMultiPolygon);
+    }
+
+    #[test]
+    pub fn test_read_and_set_points() {
+        let n = 1000;
+        let x = (0..n).map(|i| i as f64).collect::<Vec<_>>();
+        let y = (0..n).map(|i| 2.0 * i as f64).collect::<Vec<_>>();
+        let z = (0..n).map(|i| 3.0 * i as f64).collect::<Vec<_>>();
+
+        let mut line = Geometry::empty(wkbLineString).unwrap();
+        line.set_points(&x, &y, Some(&z), None).unwrap();
+        assert_eq!(line.geometry_type(), wkbLineString25D);
+        assert_eq!(line.point_count(), n);
+        assert_eq!(line.get_point(10), (10.0, 20.0, 30.0));
+
+        let (mut rx, mut ry, mut rm) = (vec![0.; n], vec![0.; n], vec![1.; n]);
+        assert_eq!(
+            line.read_points(&mut rx, &mut ry, None, Some(&mut rm))
+                .unwrap(),
+            n
+        );
+        assert_eq!((rx, ry), (x, y));
+        // the geometry has no M values
+        assert!(rm.iter().all(|&m| m == 0.0));
+
+        let mut short = vec![0.; n - 1];
+        assert!(line
+            .read_points(&mut short, &mut vec![0.; n], None, None)
+            .is_err());
+        assert!(line.set_points(&[0.0], &[0.0, 1.0], None, None).is_err());
+
+        let mut points = Vec::new();
+        assert_eq!(line.get_points(&mut points), n);
+        assert_eq!(points[999], (999.0, 1998.0, 2997.0));
+
+        // polygons have no points of their own
+        let polygon = Geometry::from_wkt("POLYGON ((0 0,0 1,1 1,0 0))").unwrap();
+        assert_eq!(
+            polygon.read_points(&mut [], &mut [], None, None).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    pub fn test_set_points_drops_z_and_m() {
+        let mut line = Geometry::from_wkt("LINESTRING Z (0 0 1,1 1 2)").unwrap();
+        line.set_points(&[5.0, 6.0, 7.0], &[8.0, 9.0, 10.0], None, None)
+            .unwrap();
+        assert!(!line.is_3d());
+        assert_eq!(line.geometry_type(), wkbLineString);
+        assert_eq!(line.get_point(2), (7.0, 10.0, 0.0));
+
+        let mut line = Geometry::from_wkt("LINESTRING M (0 0 1,1 1 2)").unwrap();
+        line.set_points(&[5.0], &[8.0], None, None).unwrap();
+        assert!(!line.is_measured());
+
+        let mut line = Geometry::from_wkt("LINESTRING ZM (0 0 1 2,1 1 3 4)").unwrap();
+        line.set_points_interleaved(&[5.0, 6.0, 7.0, 8.0], CoordinateLayout::Xy)
+            .unwrap();
+        assert!(!line.is_3d());
+        assert!(!line.is_measured());
+        assert_eq!(line.point_count(), 2);
+    }
+
+    #[test]
+    pub fn test_set_points_on_point() {
+        let mut point = Geometry::from_wkt("POINT (1 2)").unwrap();
+        assert!(point.set_points(&[], &[], None, None).is_err());
+        assert!(point
+            .set_points(&[3.0, 5.0], &[4.0, 6.0], None, None)
+            .is_err());
+        assert!(point
+            .set_points_interleaved(&[3.0, 4.0, 5.0, 6.0], CoordinateLayout::Xy)
+            .is_err());
+        assert_eq!(point.get_point(0), (1.0, 2.0, 0.0));
+
+        point
+            .set_points(&[3.0], &[4.0], Some(&[5.0]), None)
+            .unwrap();
+        assert_eq!(point.get_point(0), (3.0, 4.0, 5.0));
+    }
+
+    #[test]
+    pub fn test_read_and_set_points_interleaved() {
+        let mut line = Geometry::empty(wkbLineString).unwrap();
+        let coords = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        line.set_points_interleaved(&coords, CoordinateLayout::Xym)
+            .unwrap();
+        assert_eq!(line.geometry_type(), wkbLineStringM);
+        assert_eq!(line.point_count(), 2);
+        assert_eq!(line.get_point_zm(1), (3.0, 4.0, 0.0, 5.0));
+
+        let mut xyzm = [f64::NAN; 8];
+        assert_eq!(
+            line.read_points_interleaved(&mut xyzm, CoordinateLayout::Xyzm)
+                .unwrap(),
+            2
+        );
+        assert_eq!(xyzm, [0.0, 1.0, 0.0, 2.0, 3.0, 4.0, 0.0, 5.0]);
+
+        let mut xy = [0.0; 3];
+        assert!(line
+            .read_points_interleaved(&mut xy, CoordinateLayout::Xy)
+            .is_err());
+        assert!(line
+            .set_points_interleaved(&[0.0; 5], CoordinateLayout::Xy)
+            .is_err());
+
+        let mut point = Geometry::empty(wkbPoint).unwrap();
+        point
+            .set_points_interleaved(&[1.0, 2.0], CoordinateLayout::Xy)
+            .unwrap();
+        assert_eq!(point.get_point(0), (1.0, 2.0, 0.0));
+    }
+
     #[test]
     pub fn test_is_empty() {
         let geom = Geometry::empty(wkbMultiPolygon).unwrap();
@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, AABB};
+
+use crate::errors::*;
+use crate::vector::{Envelope, Feature, Geometry, LayerAccess, PreparedGeometry};
+
+/// Spatial relationship tested by [`LayerAccess::spatial_join`].
+///
+/// The relationship is expressed from the point of view of the layer `spatial_join` is
+/// called on: `Within` pairs features of this layer that are within a feature of the other
+/// layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinPredicate {
+    /// The two geometries share at least one point.
+    Intersects,
+    /// The geometry of this layer is within the geometry of the other layer.
+    Within,
+    /// The geometry of this layer contains the geometry of the other layer.
+    Contains,
+}
+
+/// A match returned by [`LayerAccess::nearest_join`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NearestMatch {
+    /// FID of the feature in the layer `nearest_join` was called on.
+    pub fid: u64,
+    /// FID of the matched feature in the other layer.
+    pub other_fid: u64,
+    /// Distance between the two geometries, in the units of their spatial reference.
+    pub distance: f64,
+}
+
+type IndexEntry = GeomWithData<Rectangle<[f64; 2]>, usize>;
+
+/// In-memory R-tree over the envelopes of the geometries of a layer.
+struct SpatialIndex {
+    features: Vec<(u64, Geometry)>,
+    tree: RTree<IndexEntry>,
+}
+
+impl SpatialIndex {
+    /// Read the features of `layer` that have a FID and a non-empty geometry.
+    ///
+    /// If `extent` is given and `layer` has no spatial filter, only the features intersecting
+    /// `extent` are read; the filter is cleared afterwards.
+    fn from_layer<L: LayerAccess>(layer: &mut L, extent: Option<&Envelope>) -> Self {
+        let has_filter = unsafe { !gdal_sys::OGR_L_GetSpatialFilter(layer.c_layer()).is_null() };
+        let extent = extent.filter(|_| !has_filter);
+        if let Some(e) = extent {
+            layer.set_spatial_filter_rect(e.MinX, e.MinY, e.MaxX, e.MaxY);
+        }
+
+        let mut features = Vec::new();
+        let mut entries = Vec::new();
+        for feature in layer.features() {
+            let (Some(fid), Some(geometry)) = (feature.fid(), feature_geometry(&feature)) else {
+                continue;
+            };
+            entries.push(GeomWithData::new(
+                envelope_rectangle(&geometry.envelope()),
+                features.len(),
+            ));
+            features.push((fid, geometry.clone()));
+        }
+
+        if extent.is_some() {
+            layer.clear_spatial_filter();
+        }
+
+        SpatialIndex {
+            features,
+            tree: RTree::bulk_load(entries),
+        }
+    }
+
+    /// Indices of the features whose envelope intersects `envelope` grown by `distance`, in
+    /// insertion order.
+    fn candidates(&self, envelope: &Envelope, distance: f64) -> Vec<usize> {
+        let query = AABB::from_corners(
+            [envelope.MinX - distance, envelope.MinY - distance],
+            [envelope.MaxX + distance, envelope.MaxY + distance],
+        );
+        let mut candidates = self
+            .tree
+            .locate_in_envelope_intersecting(&query)
+            .map(|entry| entry.data)
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+        candidates
+    }
+}
+
+/// Returns the geometry of `feature`, unless it is missing or empty.
+fn feature_geometry<'a>(feature: &'a Feature) -> Option<&'a Geometry> {
+    feature
+        .geometry()
+        .filter(|g| unsafe { !g.c_geometry().is_null() } && !g.is_empty())
+}
+
+fn envelope_rectangle(envelope: &Envelope) -> Rectangle<[f64; 2]> {
+    Rectangle::from_corners(
+        [envelope.MinX, envelope.MinY],
+        [envelope.MaxX, envelope.MaxY],
+    )
+}
+
+fn envelope_union(a: &Envelope, b: &Envelope) -> Envelope {
+    Envelope {
+        MinX: a.MinX.min(b.MinX),
+        MaxX: a.MaxX.max(b.MaxX),
+        MinY: a.MinY.min(b.MinY),
+        MaxY: a.MaxY.max(b.MaxY),
+    }
+}
+
+pub(crate) fn spatial_join<L: LayerAccess, R: LayerAccess>(
+    left: &mut L,
+    right: &mut R,
+    predicate: JoinPredicate,
+) -> Result<Vec<(u64, u64)>> {
+    let extent = left.try_get_extent()?;
+    let index = SpatialIndex::from_layer(right, extent.as_ref());
+    let use_prepared = PreparedGeometry::is_supported();
+    let mut prepared: Vec<Option<PreparedGeometry>> = Vec::new();
+    prepared.resize_with(index.features.len(), || None);
+
+    let mut pairs = Vec::new();
+    for feature in left.features() {
+        let (Some(fid), Some(geometry)) = (feature.fid(), feature_geometry(&feature)) else {
+            continue;
+        };
+        let candidates = index.candidates(&geometry.envelope(), 0.0);
+        if candidates.is_empty() {
+            continue;
+        }
+
+        let prepared_left = match predicate {
+            JoinPredicate::Contains if use_prepared => Some(geometry.prepare()?),
+            _ => None,
+        };
+        for i in candidates {
+            let (other_fid, other) = &index.features[i];
+            let matches = match predicate {
+                JoinPredicate::Contains => match &prepared_left {
+                    Some(p) => p.contains(other),
+                    None => geometry.contains(other),
+                },
+                JoinPredicate::Intersects | JoinPredicate::Within if use_prepared => {
+                    if prepared[i].is_none() {
+                        prepared[i] = Some(other.prepare()?);
+                    }
+                    let p = prepared[i].as_ref().unwrap();
+                    if predicate == JoinPredicate::Intersects {
+                        p.intersects(geometry)
+                    } else {
+                        p.contains(geometry)
+                    }
+                }
+                JoinPredicate::Intersects => geometry.intersects(other),
+                JoinPredicate::Within => geometry.within(other),
+            };
+            if matches {
+                pairs.push((fid, *other_fid));
+            }
+        }
+    }
+    Ok(pairs)
+}
+
+pub(crate) fn nearest_join<L: LayerAccess, R: LayerAccess>(
+    left: &mut L,
+    right: &mut R,
+    k: usize,
+    max_distance: Option<f64>,
+) -> Result<Vec<NearestMatch>> {
+    if let Some(max_distance) = max_distance {
+        if max_distance.is_nan() || max_distance < 0.0 {
+            return Err(GdalError::BadArgument(format!(
+                "max_distance must be non-negative, got {max_distance}"
+            )));
+        }
+    }
+    let index = SpatialIndex::from_layer(right, None);
+    if k == 0 || index.features.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let tree_envelope = index.tree.root().envelope();
+    let [min_x, min_y] = tree_envelope.lower();
+    let [max_x, max_y] = tree_envelope.upper();
+    let tree_envelope = Envelope {
+        MinX: min_x,
+        MaxX: max_x,
+        MinY: min_y,
+        MaxY: max_y,
+    };
+    // Start with the typical spacing between features and double it until enough matches
+    // are found.
+    let spacing = ((max_x - min_x) + (max_y - min_y)) / (index.features.len() as f64).sqrt();
+    let initial_radius = if spacing > 0.0 { spacing } else { 1.0 };
+
+    let mut matches = Vec::new();
+    for feature in left.features() {
+        let (Some(fid), Some(geometry)) = (feature.fid(), feature_geometry(&feature)) else {
+            continue;
+        };
+        let envelope = geometry.envelope();
+        let full = envelope_union(&envelope, &tree_envelope);
+
+        let mut distances = HashMap::new();
+        let mut radius = match max_distance {
+            Some(max_distance) => initial_radius.min(max_distance),
+            None => initial_radius,
+        };
+        loop {
+            for i in index.candidates(&envelope, radius) {
+                distances
+                    .entry(i)
+                    .or_insert_with(|| geometry.distance(&index.features[i].1));
+            }
+            // Every feature closer than `radius` has an envelope within `radius` of ours, so
+            // once `k` of them have been seen the nearest ones are known.
+            let found = distances
+                .values()
+                .filter(|d| matches!(d, Some(d) if *d <= radius))
+                .count();
+            let covers_all = envelope.MinX - radius <= full.MinX
+                && envelope.MinY - radius <= full.MinY
+                && envelope.MaxX + radius >= full.MaxX
+                && envelope.MaxY + radius >= full.MaxY;
+            let at_limit = max_distance.is_some_and(|max_distance| radius >= max_distance);
+            if found >= k || covers_all || at_limit {
+                break;
+            }
+            radius *= 2.0;
+            if let Some(max_distance) = max_distance {
+                radius = radius.min(max_distance);
+            }
+        }
+
+        let mut nearest = distances
+            .into_iter()
+            .filter_map(|(i, d)| Some((d?, index.features[i].0)))
+            .filter(|(d, _)| max_distance.map_or(true, |max_distance| *d <= max_distance))
+            .collect::<Vec<_>>();
+        nearest.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        matches.extend(
+            nearest
+                .into_iter()
+                .take(k)
+                .map(|(distance, other_fid)| NearestMatch {
+                    fid,
+                    other_fid,
+                    distance,
+                }),
+        );
+    }
+    Ok(matches)
+}
+
+pub(crate) fn write_joined_features<L: LayerAccess, R: LayerAccess, O: LayerAccess>(
+    left: &L,
+    right: &R,
+    pairs: &[(u64, u64)],
+    output: &mut O,
+) -> Result<()> {
+    let field_names = output
+        .defn()
+        .fields()
+        .map(|field| field.name())
+        .collect::<Vec<_>>();
+    let missing = |fid: u64| GdalError::BadArgument(format!("feature {fid} not found"));
+
+    for &(fid, other_fid) in pairs {
+        let left_feature = left.feature(fid).ok_or_else(|| missing(fid))?;
+        let right_feature = right.feature(other_fid).ok_or_else(|| missing(other_fid))?;
+
+        let mut feature = Feature::new(output.defn())?;
+        for (idx, name) in field_names.iter().enumerate() {
+            let value = match left_feature.field_index(name) {
+                Ok(i) => left_feature.field(i)?,
+                Err(_) => match right_feature.field_index(name) {
+                    Ok(i) => right_feature.field(i)?,
+                    Err(_) => None,
+                },
+            };
+            if let Some(value) = value {
+                feature.set_field(idx, &value)?;
+            }
+        }
+        if let Some(geometry) = feature_geometry(&left_feature) {
+            feature.set_geometry(geometry.clone())?;
+        }
+        feature.create(output)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::{LayerOptions, OGRFieldType, OGRwkbGeometryType};
+    use crate::{Dataset, DriverManager};
+
+    fn join_dataset() -> Dataset {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+
+        let points = ds
+            .create_layer(LayerOptions {
+                name: "points",
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+        points
+            .create_defn_fields(&[("name", OGRFieldType::OFTString)])
+            .unwrap();
+        for (name, wkt) in [
+            ("a", "POINT (1 1)"),
+            ("b", "POINT (5 5)"),
+            ("c", "POINT (12 1)"),
+            ("d", "POINT (30 30)"),
+        ] {
+            let mut feature = Feature::new(points.defn()).unwrap();
+            feature.set_field_string(0, name).unwrap();
+            feature
+                .set_geometry(Geometry::from_wkt(wkt).unwrap())
+                .unwrap();
+            feature.create(&points).unwrap();
+        }
+
+        let zones = ds
+            .create_layer(LayerOptions {
+                name: "zones",
+                ty: OGRwkbGeometryType::wkbPolygon,
+                ..Default::default()
+            })
+            .unwrap();
+        zones
+            .create_defn_fields(&[("zone", OGRFieldType::OFTInteger)])
+            .unwrap();
+        for (zone, wkt) in [
+            (1, "POLYGON ((0 0,0 10,10 10,10 0,0 0))"),
+            (2, "POLYGON ((4 4,4 6,6 6,6 4,4 4))"),
+            (3, "POLYGON ((20 0,20 2,22 2,22 0,20 0))"),
+        ] {
+            let mut feature = Feature::new(zones.defn()).unwrap();
+            feature.set_field_integer(0, zone).unwrap();
+            feature
+                .set_geometry(Geometry::from_wkt(wkt).unwrap())
+                .unwrap();
+            feature.create(&zones).unwrap();
+        }
+        ds
+    }
+
+    #[test]
+    fn test_spatial_join() {
+        let ds = join_dataset();
+        let mut points = ds.layer_by_name("points").unwrap();
+        let mut zones = ds.layer_by_name("zones").unwrap();
+
+        let pairs = points
+            .spatial_join(&mut zones, JoinPredicate::Within)
+            .unwrap();
+        assert_eq!(pairs, [(0, 0), (1, 0), (1, 1)]);
+
+        let pairs = zones
+            .spatial_join(&mut points, JoinPredicate::Contains)
+            .unwrap();
+        assert_eq!(pairs, [(0, 0), (0, 1), (1, 1)]);
+
+        let pairs = points
+            .spatial_join(&mut zones, JoinPredicate::Intersects)
+            .unwrap();
+        assert_eq!(pairs, [(0, 0), (1, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn test_nearest_join() {
+        let ds = join_dataset();
+        let mut points = ds.layer_by_name("points").unwrap();
+        let mut zones = ds.layer_by_name("zones").unwrap();
+
+        let matches = points.nearest_join(&mut zones, 1, None).unwrap();
+        let nearest = matches
+            .iter()
+            .map(|m| (m.fid, m.other_fid))
+            .collect::<Vec<_>>();
+        assert_eq!(nearest, [(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(matches[2].distance, 2.0);
+
+        let matches = points.nearest_join(&mut zones, 2, Some(5.0)).unwrap();
+        let nearest = matches
+            .iter()
+            .map(|m| (m.fid, m.other_fid, m.distance))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            nearest,
+            [
+                (0, 0, 0.0),
+                (0, 1, 18f64.sqrt()),
+                (1, 0, 0.0),
+                (1, 1, 0.0),
+                (2, 0, 2.0)
+            ]
+        );
+
+        assert!(points.nearest_join(&mut zones, 1, Some(-1.0)).is_err());
+    }
+
+    #[test]
+    fn test_write_joined_features() {
+        let mut ds = join_dataset();
+        let output = ds
+            .create_layer(LayerOptions {
+                name: "joined",
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+        output
+            .create_defn_fields(&[
+                ("name", OGRFieldType::OFTString),
+                ("zone", OGRFieldType::OFTInteger),
+            ])
+            .unwrap();
+
+        let mut points = ds.layer_by_name("points").unwrap();
+        let mut zones = ds.layer_by_name("zones").unwrap();
+        let mut output = ds.layer_by_name("joined").unwrap();
+        let pairs = points
+            .spatial_join(&mut zones, JoinPredicate::Within)
+            .unwrap();
+        points
+            .write_joined_features(&zones, &pairs, &mut output)
+            .unwrap();
+
+        let rows = output
+            .features()
+            .map(|f| {
+                (
+                    f.field_as_string(0).unwrap().unwrap(),
+                    f.field_as_integer(1).unwrap().unwrap(),
+                    f.geometry().unwrap().wkt().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                ("a".to_string(), 1, "POINT (1 1)".to_string()),
+                ("b".to_string(), 1, "POINT (5 5)".to_string()),
+                ("b".to_string(), 2, "POINT (5 5)".to_string()),
+            ]
+        );
+    }
+}
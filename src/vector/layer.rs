@@ -5,7 +5,9 @@ use std::{
     ptr::null_mut,
 };
 
-use gdal_sys::{GDALMajorObjectH, OGRErr, OGRFieldDefnH, OGRFieldType, OGRLayerH};
+use gdal_sys::{
+    GDALMajorObjectH, OGRErr, OGRFieldDefnH, OGRFieldType, OGRLayerH, OGRwkbGeometryType,
+};
 
 use crate::errors::*;
 use crate::metadata::Metadata;
@@ -13,7 +15,11 @@ use crate::spatial_ref::SpatialRef;
 use crate::utils::{_last_null_pointer_err, _string};
 use crate::vector::defn::Defn;
 use crate::vector::feature::{FeatureIterator, OwnedFeatureIterator};
-use crate::vector::{Envelope, Feature, Geometry, LayerOptions};
+#[cfg(feature = "join")]
+use crate::vector::join::{self, JoinPredicate, NearestMatch};
+use crate::vector::{
+    geometry_type_collection, geometry_type_flatten, Envelope, Feature, Geometry, LayerOptions,
+};
 use crate::{dataset::Dataset, gdal_major_object::MajorObject};
 
 /// Layer capabilities
@@ -277,6 +283,60 @@ pub trait LayerAccess: Sized {
         unsafe { gdal_sys::OGR_L_SetSpatialFilter(self.c_layer(), null_mut()) };
     }
 
+    /// Returns the pairs of FIDs `(fid, other_fid)` of the features of this layer and
+    /// `other` whose geometries satisfy `predicate`.
+    ///
+    /// The features of `other` are loaded into an in-memory R-tree, so it should usually be
+    /// the smaller layer. Unless `other` already has a spatial filter, only its features
+    /// intersecting the extent of this layer are read. Candidates are then tested with
+    /// [prepared geometries](crate::vector::PreparedGeometry) when GDAL is built with GEOS.
+    ///
+    /// Pairs are returned in the iteration order of this layer, then of `other`. Features
+    /// without a FID or with an empty geometry are skipped.
+    #[cfg(feature = "join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "join")))]
+    fn spatial_join<L: LayerAccess>(
+        &mut self,
+        other: &mut L,
+        predicate: JoinPredicate,
+    ) -> Result<Vec<(u64, u64)>> {
+        join::spatial_join(self, other, predicate)
+    }
+
+    /// Returns, for each feature of this layer, the `k` nearest features of `other`, closest
+    /// first, optionally limited to those within `max_distance`.
+    ///
+    /// The features of `other` are loaded into an in-memory R-tree, which is searched with a
+    /// growing radius until `k` matches are known; distances are then computed with
+    /// [`Geometry::distance`]. Ties are broken by FID.
+    #[cfg(feature = "join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "join")))]
+    fn nearest_join<L: LayerAccess>(
+        &mut self,
+        other: &mut L,
+        k: usize,
+        max_distance: Option<f64>,
+    ) -> Result<Vec<NearestMatch>> {
+        join::nearest_join(self, other, k, max_distance)
+    }
+
+    /// Write a feature to `output` for each pair of FIDs `(fid, other_fid)`, as returned by
+    /// [`LayerAccess::spatial_join`].
+    ///
+    /// The new features get the geometry of the feature of this layer. Each field of `output`
+    /// is copied from the field of the same name of the feature of this layer if there is one,
+    /// or else from the feature of `other`.
+    #[cfg(feature = "join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "join")))]
+    fn write_joined_features<L: LayerAccess, O: LayerAccess>(
+        &self,
+        other: &L,
+        pairs: &[(u64, u64)],
+        output: &mut O,
+    ) -> Result<()> {
+        join::write_joined_features(self, other, pairs, output)
+    }
+
     /// Get the name of this layer.
     fn name(&self) -> String {
         let rv = unsafe { gdal_sys::OGR_L_GetName(self.c_layer()) };
@@ -296,6 +356,10 @@ pub trait LayerAccess: Sized {
         }
         Ok(())
     }
+
+    /// Create a new feature with the given geometry in this layer.
+    ///
+    /// See: [`OGR_L_CreateFeature`](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_L_CreateFeature9OGRLayerH10OGRFeatureH)
     fn create_feature(&mut self, geometry: Geometry) -> Result<()> {
         let feature = Feature::new(self.defn())?;
 
@@ -317,6 +381,26 @@ pub trait LayerAccess: Sized {
         Ok(())
     }
 
+    /// Create a new feature with the given geometry in this layer, promoting single-part
+    /// geometries to the layer type.
+    ///
+    /// If the layer has a multi-part geometry type (e.g. it was created with
+    /// [`LayerOptions::promote_to_multi`]), single-part geometries of the corresponding type
+    /// are promoted with [`Geometry::force_to_multi`] before being written. Other geometries
+    /// are written unchanged, like with [`create_feature`](Self::create_feature).
+    fn create_feature_promoted(&mut self, geometry: Geometry) -> Result<()> {
+        let layer_type = geometry_type_flatten(self.defn().geometry_type());
+        let geometry_type = geometry_type_flatten(geometry.geometry_type());
+        let geometry = if geometry_type != layer_type
+            && geometry_type_flatten(geometry_type_collection(geometry_type)) == layer_type
+        {
+            geometry.force_to_multi()
+        } else {
+            geometry
+        };
+        self.create_feature(geometry)
+    }
+
     /// Returns the number of features in this layer, even if it requires expensive calculation.
     ///
     /// Some drivers will actually scan the entire layer once to count objects.
@@ -693,20 +777,57 @@ impl Dataset {
             std::ptr::null()
         };
 
-        let c_layer = unsafe {
-            // The C function takes `char **papszOptions` without mention of `const`, and this is
-            // propagated to the gdal_sys wrapper. The lack of `const` seems like a mistake in the
-            // GDAL API, so we just do a cast here.
-            gdal_sys::GDALDatasetCreateLayer(
-                self.c_dataset(),
-                c_name.as_ptr(),
-                c_srs,
-                options.ty,
-                c_options_ptr as *mut *mut c_char,
-            )
+        let ty = if options.promote_to_multi {
+            let multi_ty = geometry_type_collection(options.ty);
+            if geometry_type_flatten(multi_ty) == OGRwkbGeometryType::wkbUnknown {
+                options.ty
+            } else {
+                multi_ty
+            }
+        } else {
+            options.ty
+        };
+
+        let (c_layer, method_name) = match options.coordinate_precision {
+            None => unsafe {
+                // The C function takes `char **papszOptions` without mention of `const`, and this is
+                // propagated to the gdal_sys wrapper. The lack of `const` seems like a mistake in the
+                // GDAL API, so we just do a cast here.
+                let c_layer = gdal_sys::GDALDatasetCreateLayer(
+                    self.c_dataset(),
+                    c_name.as_ptr(),
+                    c_srs,
+                    ty,
+                    c_options_ptr as *mut *mut c_char,
+                );
+                (c_layer, "GDALDatasetCreateLayer")
+            },
+            #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+            Some(precision) => unsafe {
+                // the geometry field definition and the precision are copied by GDAL
+                let c_geom_field = gdal_sys::OGR_GFld_Create(c"".as_ptr(), ty);
+                gdal_sys::OGR_GFld_SetSpatialRef(c_geom_field, c_srs);
+                let c_precision = precision.to_c_precision();
+                gdal_sys::OGR_GFld_SetCoordinatePrecision(c_geom_field, c_precision);
+                gdal_sys::OGRGeomCoordinatePrecisionDestroy(c_precision);
+                let c_layer = gdal_sys::GDALDatasetCreateLayerFromGeomFieldDefn(
+                    self.c_dataset(),
+                    c_name.as_ptr(),
+                    c_geom_field,
+                    c_options_ptr as *mut *mut c_char,
+                );
+                gdal_sys::OGR_GFld_Destroy(c_geom_field);
+                (c_layer, "GDALDatasetCreateLayerFromGeomFieldDefn")
+            },
+            #[cfg(not(any(major_ge_4, all(major_is_3, minor_ge_9))))]
+            Some(_) => {
+                return Err(GdalError::BadArgument(
+                    "LayerOptions::coordinate_precision requires GDAL 3.9 or later".to_string(),
+                ))
+            }
         };
         if c_layer.is_null() {
-            return Err(_last_null_pointer_err("GDALDatasetCreateLayer"));
+            return Err(_last_null_pointer_err(method_name));
         };
         Ok(self.child_layer(c_layer))
     }
@@ -803,6 +924,92 @@ mod tests {
         assert!(ds.create_layer(options).is_ok());
     }
 
+    #[test]
+    fn test_create_layer_promote_to_multi() {
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let mut ds = driver
+            .create_vector_only("/vsimem/test_promote_to_multi.gpkg")
+            .unwrap();
+        let mut layer = ds
+            .create_layer(LayerOptions {
+                name: "polygons",
+                ty: OGRwkbGeometryType::wkbPolygon,
+                promote_to_multi: true,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(
+            layer.defn().geometry_type(),
+            OGRwkbGeometryType::wkbMultiPolygon
+        );
+
+        let polygon = Geometry::from_wkt("POLYGON ((0 0,0 1,1 1,0 0))").unwrap();
+        let multi_polygon =
+            Geometry::from_wkt("MULTIPOLYGON (((0 0,0 1,1 1,0 0)),((2 2,2 3,3 3,2 2)))").unwrap();
+        layer.create_feature_promoted(polygon.clone()).unwrap();
+        layer.create_feature_promoted(multi_polygon).unwrap();
+        // no promotion unless asked for
+        layer.create_feature(polygon).unwrap();
+
+        let types = layer
+            .features()
+            .map(|f| f.geometry().unwrap().geometry_type())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            [
+                OGRwkbGeometryType::wkbMultiPolygon,
+                OGRwkbGeometryType::wkbMultiPolygon,
+                OGRwkbGeometryType::wkbPolygon
+            ]
+        );
+    }
+
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    #[test]
+    fn test_create_layer_coordinate_precision() {
+        use crate::vector::CoordinatePrecision;
+
+        let precision = CoordinatePrecision {
+            xy_resolution: 0.01,
+            z_resolution: 0.1,
+            m_resolution: 0.0,
+        };
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let path = "/vsimem/test_coordinate_precision.gpkg";
+        {
+            let mut ds = driver.create_vector_only(path).unwrap();
+            let mut layer = ds
+                .create_layer(LayerOptions {
+                    name: "points",
+                    srs: Some(&SpatialRef::from_epsg(3857).unwrap()),
+                    ty: OGRwkbGeometryType::wkbPoint25D,
+                    coordinate_precision: Some(precision),
+                    ..Default::default()
+                })
+                .unwrap();
+            let field = layer.defn().geom_fields().next().unwrap();
+            assert_eq!(field.coordinate_precision(), precision);
+
+            let point = Geometry::from_wkt("POINT Z (1.23456 2.34567 3.45678)").unwrap();
+            layer.create_feature(point).unwrap();
+        }
+
+        {
+            let ds = Dataset::open(path).unwrap();
+            let mut layer = ds.layer(0).unwrap();
+            let field = layer.defn().geom_fields().next().unwrap();
+            assert_eq!(field.coordinate_precision(), precision);
+            let feature = layer.features().next().unwrap();
+            let (x, y, z) = feature.geometry().unwrap().get_point(0);
+            // rounded to the resolution, with some least significant bits also discarded
+            assert!(x != 1.23456 && (x - 1.23).abs() < 0.01);
+            assert!(y != 2.34567 && (y - 2.35).abs() < 0.01);
+            assert!(z != 3.45678 && (z - 3.5).abs() < 0.1);
+        }
+        driver.delete(path).unwrap();
+    }
+
     #[test]
     fn test_layer_count() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
@@ -1592,6 +1799,8 @@ mod tests {
                     ty: OGRwkbGeometryType::wkbNone,
                     srs: None,
                     options: Some(&["OVERWRITE=YES"]),
+                    promote_to_multi: false,
+                    coordinate_precision: None,
                 })
                 .expect("create layer");
             rivers.create_defn_fields(&[]).expect("define fields");
@@ -1611,6 +1820,8 @@ mod tests {
                     ty: OGRwkbGeometryType::wkbPolygon,
                     srs: Some(&SpatialRef::from_epsg(4326).expect("srs")),
                     options: Some(&["OVERWRITE=YES"]),
+                    promote_to_multi: false,
+                    coordinate_precision: None,
                 })
                 .expect("create layer");
 
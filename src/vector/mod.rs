@@ -67,6 +67,8 @@
 mod defn;
 mod feature;
 mod geometry;
+#[cfg(feature = "join")]
+mod join;
 mod layer;
 mod ops;
 mod options;
@@ -80,9 +82,14 @@ pub use feature::{
 };
 pub use gdal_sys::{OGRFieldType, OGRwkbGeometryType};
 pub use geometry::{
-    geometry_type_flatten, geometry_type_has_m, geometry_type_has_z, geometry_type_set_m,
-    geometry_type_set_modifier, geometry_type_set_z, geometry_type_to_name, Geometry, GeometryRef,
+    geometry_type_collection, geometry_type_curve, geometry_type_flatten, geometry_type_has_m,
+    geometry_type_has_z, geometry_type_is_non_linear, geometry_type_linear, geometry_type_set_m,
+    geometry_type_set_modifier, geometry_type_set_z, geometry_type_to_name, CoordinateLayout,
+    CoordinatePrecision, Geometry, GeometryRef,
 };
+#[cfg(feature = "join")]
+#[cfg_attr(docsrs, doc(cfg(feature = "join")))]
+pub use join::{JoinPredicate, NearestMatch};
 pub use layer::{FieldDefn, Layer, LayerAccess, LayerCaps, LayerIterator, OwnedLayer};
 pub use options::LayerOptions;
 pub use transaction::Transaction;
@@ -93,4 +100,9 @@ pub type Envelope = gdal_sys::OGREnvelope;
 /// Axis aligned 3D bounding box.
 pub type Envelope3D = gdal_sys::OGREnvelope3D;
 
-pub use ops::ToGdal;
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+pub use ops::SetPrecisionFlags;
+pub use ops::{
+    PreparedGeometry, ToGdal, ValidityReason, ValidityReasonKind, WkbByteOrder, WkbOptions,
+    WkbVariant, WktOptions, ZmValues,
+};
@@ -1,15 +1,109 @@
 use std::{
     ffi::{c_char, c_void, CString},
-    ptr::null_mut,
+    ptr::{self, null_mut},
 };
 
-use gdal_sys::OGRErr;
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+use gdal_sys::OGRwkbVariant;
+use gdal_sys::{OGRErr, OGRwkbByteOrder};
 
+use crate::config::{
+    clear_thread_local_config_option, get_thread_local_config_option,
+    set_thread_local_config_option,
+};
+use crate::cpl::CslStringList;
 use crate::errors::GdalError;
 use crate::errors::Result;
 use crate::utils::{_last_null_pointer_err, _string};
+use crate::vector::CoordinatePrecision;
 use crate::vector::Geometry;
 
+/// Byte order of [WKB](Geometry::wkb_with_options) output.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum WkbByteOrder {
+    /// Least significant byte first (NDR).
+    #[default]
+    LittleEndian,
+    /// Most significant byte first (XDR).
+    BigEndian,
+}
+
+impl From<WkbByteOrder> for OGRwkbByteOrder::Type {
+    fn from(value: WkbByteOrder) -> Self {
+        match value {
+            WkbByteOrder::LittleEndian => OGRwkbByteOrder::wkbNDR,
+            WkbByteOrder::BigEndian => OGRwkbByteOrder::wkbXDR,
+        }
+    }
+}
+
+/// Flavor of [WKB](Geometry::wkb_with_options) output, which differ in how they encode
+/// geometry types with Z or M values, and non-linear geometry types.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum WkbVariant {
+    /// OGC 1.1 WKB, with the GDAL "extended" type codes for Z and curve geometries,
+    /// as produced by [`Geometry::wkb`].
+    #[default]
+    OldOgc,
+    /// SFSQL 1.2 / ISO SQL / MM Part 3 WKB, as produced by [`Geometry::iso_wkb`].
+    Iso,
+    /// PostGIS 1.x WKB.
+    PostGis1,
+}
+
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+impl From<WkbVariant> for OGRwkbVariant::Type {
+    fn from(value: WkbVariant) -> Self {
+        match value {
+            WkbVariant::OldOgc => OGRwkbVariant::wkbVariantOldOgc,
+            WkbVariant::Iso => OGRwkbVariant::wkbVariantIso,
+            WkbVariant::PostGis1 => OGRwkbVariant::wkbVariantPostGIS1,
+        }
+    }
+}
+
+/// Options for [`Geometry::wkb_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct WkbOptions {
+    /// Byte order of the output.
+    pub byte_order: WkbByteOrder,
+    /// WKB flavor.
+    ///
+    /// [`WkbVariant::PostGis1`] requires GDAL 3.9 or later.
+    pub variant: WkbVariant,
+    /// Round the coordinates to this precision. The rounding also zeroes the least
+    /// significant bits of the coordinates, which makes the output compress better.
+    ///
+    /// Requires GDAL 3.9 or later; setting it with older versions is an error.
+    pub precision: Option<CoordinatePrecision>,
+}
+
+/// Options for [`Geometry::wkt_with_options`].
+#[derive(Debug, Clone)]
+pub struct WktOptions {
+    /// Write SFSQL 1.2 / ISO SQL / MM Part 3 WKT, as [`Geometry::iso_wkt`] does,
+    /// instead of OGC 1.1 WKT.
+    pub iso: bool,
+    /// Number of significant digits of the coordinates (decimal digits for coordinates whose
+    /// absolute value is less than 1), or `None` for the default of 15 (which can be set with
+    /// the `OGR_WKT_PRECISION` configuration option).
+    pub precision: Option<u8>,
+    /// Remove the trailing digits of coordinates that look like rounding errors,
+    /// e.g. `0.3` instead of `0.30000000000000004`.
+    pub round: bool,
+}
+
+impl Default for WktOptions {
+    /// Returns options for OGC 1.1 WKT with GDAL's default precision and rounding.
+    fn default() -> Self {
+        Self {
+            iso: false,
+            precision: None,
+            round: true,
+        }
+    }
+}
+
 /// Methods supporting translation between GDAL [`Geometry`] and various text representations.
 ///
 /// These include:
@@ -112,6 +206,41 @@ impl Geometry {
         Ok(wkt)
     }
 
+    /// Serialize the geometry as WKT, with control over the WKT flavor and the formatting
+    /// of the coordinates.
+    ///
+    /// See: [`OGR_G_ExportToWkt`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_ExportToWkt12OGRGeometryHPPc)
+    pub fn wkt_with_options(&self, options: &WktOptions) -> Result<String> {
+        let mut config = vec![(
+            "OGR_WKT_ROUND",
+            if options.round { "YES" } else { "NO" }.to_string(),
+        )];
+        if let Some(precision) = options.precision {
+            config.push(("OGR_WKT_PRECISION", precision.to_string()));
+        }
+        // the options are set for this thread only, and restored afterwards
+        let previous = config
+            .iter()
+            .map(|(key, _)| get_thread_local_config_option(key, ""))
+            .collect::<Result<Vec<_>>>()?;
+        for (key, value) in &config {
+            set_thread_local_config_option(key, value)?;
+        }
+        let wkt = if options.iso {
+            self.iso_wkt()
+        } else {
+            self.wkt()
+        };
+        for ((key, _), previous) in config.iter().zip(previous) {
+            if previous.is_empty() {
+                clear_thread_local_config_option(key)?;
+            } else {
+                set_thread_local_config_option(key, &previous)?;
+            }
+        }
+        wkt
+    }
+
     /// Serializes the geometry to
     /// [WKB](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry#Well-known_binary)
     /// (Well-Known Binary) format.
@@ -157,6 +286,70 @@ impl Geometry {
         Ok(wkb)
     }
 
+    /// Serializes the geometry to
+    /// [WKB](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry#Well-known_binary)
+    /// (Well-Known Binary) format, with control over the byte order, the WKB flavor and,
+    /// with GDAL 3.9 or later, the coordinate precision.
+    ///
+    /// See: [`OGR_G_ExportToWkbEx`](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_ExportToWkbEx12OGRGeometryHPhPK19OGRwkbExportOptions)
+    pub fn wkb_with_options(&self, options: &WkbOptions) -> Result<Vec<u8>> {
+        let wkb_size = unsafe { gdal_sys::OGR_G_WkbSize(self.c_geometry()) as usize };
+        let mut wkb = vec![0; wkb_size];
+        let byte_order = options.byte_order.into();
+
+        #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+        let (rv, method_name) = unsafe {
+            let c_options = gdal_sys::OGRwkbExportOptionsCreate();
+            gdal_sys::OGRwkbExportOptionsSetByteOrder(c_options, byte_order);
+            gdal_sys::OGRwkbExportOptionsSetVariant(c_options, options.variant.into());
+            let c_precision = options.precision.map(|p| p.to_c_precision());
+            if let Some(c_precision) = c_precision {
+                gdal_sys::OGRwkbExportOptionsSetPrecision(c_options, c_precision);
+            }
+            let rv = gdal_sys::OGR_G_ExportToWkbEx(self.c_geometry(), wkb.as_mut_ptr(), c_options);
+            if let Some(c_precision) = c_precision {
+                gdal_sys::OGRGeomCoordinatePrecisionDestroy(c_precision);
+            }
+            gdal_sys::OGRwkbExportOptionsDestroy(c_options);
+            (rv, "OGR_G_ExportToWkbEx")
+        };
+
+        #[cfg(not(any(major_ge_4, all(major_is_3, minor_ge_9))))]
+        if options.precision.is_some() {
+            return Err(GdalError::BadArgument(
+                "WkbOptions::precision requires GDAL 3.9 or later".to_string(),
+            ));
+        }
+        #[cfg(not(any(major_ge_4, all(major_is_3, minor_ge_9))))]
+        let (rv, method_name) = match options.variant {
+            WkbVariant::OldOgc => (
+                unsafe {
+                    gdal_sys::OGR_G_ExportToWkb(self.c_geometry(), byte_order, wkb.as_mut_ptr())
+                },
+                "OGR_G_ExportToWkb",
+            ),
+            WkbVariant::Iso => (
+                unsafe {
+                    gdal_sys::OGR_G_ExportToIsoWkb(self.c_geometry(), byte_order, wkb.as_mut_ptr())
+                },
+                "OGR_G_ExportToIsoWkb",
+            ),
+            WkbVariant::PostGis1 => {
+                return Err(GdalError::BadArgument(
+                    "PostGIS 1 WKB requires GDAL 3.9 or later".to_string(),
+                ))
+            }
+        };
+
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name,
+            });
+        }
+        Ok(wkb)
+    }
+
     /// Serialize the geometry as GeoJSON.
     ///
     /// See: [`OGR_G_ExportToJson`](https://gdal.org/api/vector_c_api.html#_CPPv418OGR_G_ExportToJson12OGRGeometryH)
@@ -166,6 +359,48 @@ impl Geometry {
         unsafe { gdal_sys::VSIFree(c_json as *mut c_void) };
         rv
     }
+
+    /// Serialize the geometry as GeoJSON, with options such as:
+    ///
+    /// * `COORDINATE_PRECISION=int`: maximum number of figures after the decimal separator
+    /// * `SIGNIFICANT_FIGURES=int`: maximum number of significant figures
+    /// * `RFC7946=YES`: follow the [RFC 7946](https://www.rfc-editor.org/rfc/rfc7946) winding
+    ///   order of polygon rings, and split geometries crossing the anti-meridian
+    ///
+    /// See: [`OGR_G_ExportToJsonEx`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_ExportToJsonEx12OGRGeometryHPPc)
+    pub fn json_with_options(&self, options: &CslStringList) -> Result<String> {
+        let c_json = unsafe { gdal_sys::OGR_G_ExportToJsonEx(self.c_geometry(), options.as_ptr()) };
+        let rv = _string(c_json).ok_or_else(|| _last_null_pointer_err("OGR_G_ExportToJsonEx"));
+        unsafe { gdal_sys::VSIFree(c_json as *mut c_void) };
+        rv
+    }
+
+    /// Serialize the geometry as a
+    /// [KML](https://en.wikipedia.org/wiki/Keyhole_Markup_Language) fragment.
+    ///
+    /// See: [`OGR_G_ExportToKML`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_ExportToKML12OGRGeometryHPKc)
+    pub fn kml(&self) -> Result<String> {
+        let c_kml = unsafe { gdal_sys::OGR_G_ExportToKML(self.c_geometry(), ptr::null()) };
+        let rv = _string(c_kml).ok_or_else(|| _last_null_pointer_err("OGR_G_ExportToKML"));
+        unsafe { gdal_sys::VSIFree(c_kml as *mut c_void) };
+        rv
+    }
+
+    /// Serialize the geometry as a
+    /// [GML](https://en.wikipedia.org/wiki/Geography_Markup_Language) fragment, with options
+    /// such as:
+    ///
+    /// * `FORMAT=GML2/GML3/GML32`: the GML version (GML 2 by default)
+    /// * `SRSNAME_FORMAT=SHORT/OGC_URN/OGC_URL`: how to write the SRS name
+    /// * `GMLID=identifier`: the `gml:id` of the top-level element (GML 3.2)
+    ///
+    /// See: [`OGR_G_ExportToGMLEx`](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_ExportToGMLEx12OGRGeometryHPPc)
+    pub fn gml(&self, options: &CslStringList) -> Result<String> {
+        let c_gml = unsafe { gdal_sys::OGR_G_ExportToGMLEx(self.c_geometry(), options.as_ptr()) };
+        let rv = _string(c_gml).ok_or_else(|| _last_null_pointer_err("OGR_G_ExportToGMLEx"));
+        unsafe { gdal_sys::VSIFree(c_gml as *mut c_void) };
+        rv
+    }
 }
 
 #[cfg(test)]
@@ -215,4 +450,98 @@ mod tests {
         let res = Geometry::from_gml(json);
         assert!(res.is_err());
     }
+
+    #[test]
+    pub fn test_wkb_with_options() {
+        let geom = Geometry::from_wkt("POINT Z (1 2 3)").unwrap();
+
+        let options = WkbOptions {
+            byte_order: WkbByteOrder::BigEndian,
+            variant: WkbVariant::Iso,
+            ..Default::default()
+        };
+        let wkb = geom.wkb_with_options(&options).unwrap();
+        assert_eq!(wkb[0], 0);
+        // ISO type code of a Point Z
+        assert_eq!(wkb[1..5], 1001u32.to_be_bytes());
+        assert_eq!(Geometry::from_wkb(&wkb).unwrap(), geom);
+
+        let wkb = geom.wkb_with_options(&WkbOptions::default()).unwrap();
+        assert_eq!(wkb, geom.wkb().unwrap());
+        assert_eq!(wkb[0], 1);
+        // extended type code of a Point Z
+        assert_eq!(wkb[1..5], 0x80000001u32.to_le_bytes());
+    }
+
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    #[test]
+    pub fn test_wkb_with_precision() {
+        let geom = Geometry::from_wkt("POINT (1.23456 2.34567)").unwrap();
+        let options = WkbOptions {
+            precision: Some(CoordinatePrecision {
+                xy_resolution: 0.01,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let wkb = geom.wkb_with_options(&options).unwrap();
+        let (x, y, _) = Geometry::from_wkb(&wkb).unwrap().get_point(0);
+        assert!((x - 1.23).abs() < 0.01 && x != 1.23456);
+        assert!((y - 2.35).abs() < 0.01 && y != 2.34567);
+    }
+
+    #[test]
+    pub fn test_wkt_with_options() {
+        let geom = Geometry::from_wkt("POINT Z (1.23456 2 3)").unwrap();
+        assert_eq!(
+            geom.wkt_with_options(&WktOptions::default()).unwrap(),
+            geom.wkt().unwrap()
+        );
+
+        let options = WktOptions {
+            iso: true,
+            precision: Some(2),
+            ..Default::default()
+        };
+        let wkt = geom.wkt_with_options(&options).unwrap();
+        assert!(wkt.starts_with("POINT Z ("), "{wkt}");
+        assert!(!wkt.contains("1.23456"), "{wkt}");
+        // 2 significant digits, as the coordinates are not less than 1
+        let (x, _, _) = Geometry::from_wkt(&wkt).unwrap().get_point(0);
+        assert_eq!(x, 1.2);
+
+        // the thread-local configuration is restored
+        assert!(geom.wkt().unwrap().contains("1.23456"));
+    }
+
+    #[test]
+    pub fn test_json_with_options() {
+        let geom = Geometry::from_wkt("POINT (1.23456 2)").unwrap();
+        let mut options = CslStringList::new();
+        options.set_name_value("COORDINATE_PRECISION", "2").unwrap();
+        let json = geom.json_with_options(&options).unwrap();
+        assert!(json.contains("1.23"), "{json}");
+        assert!(!json.contains("1.234"), "{json}");
+    }
+
+    #[test]
+    pub fn test_kml() {
+        let geom = Geometry::from_wkt("POINT (1 2)").unwrap();
+        let kml = geom.kml().unwrap();
+        assert!(kml.contains("<Point>"), "{kml}");
+        assert!(kml.contains("<coordinates>1,2</coordinates>"), "{kml}");
+    }
+
+    #[test]
+    pub fn test_gml_export() {
+        let geom = Geometry::from_wkt("LINESTRING (1 2,3 4)").unwrap();
+        let gml = geom.gml(&CslStringList::new()).unwrap();
+        assert!(gml.starts_with("<gml:LineString>"), "{gml}");
+        assert_eq!(Geometry::from_gml(&gml).unwrap(), geom);
+
+        let mut options = CslStringList::new();
+        options.set_name_value("FORMAT", "GML3").unwrap();
+        let gml = geom.gml(&options).unwrap();
+        assert!(gml.contains("<gml:posList>1 2 3 4</gml:posList>"), "{gml}");
+    }
 }
@@ -2,14 +2,18 @@ use std::convert::{TryFrom, TryInto};
 
 use gdal_sys::OGRwkbGeometryType;
 
+use crate::cpl::CslStringList;
 use crate::errors::GdalError;
-use crate::vector::Geometry;
+use crate::vector::{geometry_type_has_m, geometry_type_has_z, Geometry};
 
 impl TryFrom<&Geometry> for geo_types::Geometry<f64> {
     type Error = GdalError;
 
     fn try_from(geo: &Geometry) -> Result<geo_types::Geometry<f64>, Self::Error> {
         let geometry_type = geo.geometry_type();
+        if geometry_type_has_z(geometry_type) || geometry_type_has_m(geometry_type) {
+            return Err(GdalError::LossyGeometryConversion(geometry_type));
+        }
 
         let ring = |n: usize| {
             let ring = unsafe { geo.get_unowned_geometry(n) };
@@ -107,6 +111,15 @@ impl TryFrom<&Geometry> for geo_types::Geometry<f64> {
                     geo_types::GeometryCollection(geometry_list),
                 ))
             }
+            OGRwkbGeometryType::wkbCircularString
+            | OGRwkbGeometryType::wkbCompoundCurve
+            | OGRwkbGeometryType::wkbCurvePolygon
+            | OGRwkbGeometryType::wkbMultiCurve
+            | OGRwkbGeometryType::wkbMultiSurface => {
+                // geo-types has no curves, so approximate them with the default arc step
+                geo.get_linear_geometry(0.0, &CslStringList::new())?
+                    .try_into()
+            }
             _ => Err(GdalError::UnsupportedGdalGeometryType(geometry_type)),
         }
     }
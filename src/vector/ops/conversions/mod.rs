@@ -1,17 +1,39 @@
 mod formats;
 mod gdal_to_geo;
 mod geo_to_gdal;
+mod zm;
 
 use crate::errors::Result;
 use crate::vector::Geometry;
 
+pub use formats::{WkbByteOrder, WkbOptions, WkbVariant, WktOptions};
+pub use zm::ZmValues;
+
 /// Convert object to a GDAL geometry.
 pub trait ToGdal {
     fn to_gdal(&self) -> Result<Geometry>;
+
+    /// Convert object to a GDAL geometry, restoring Z and M values from `zm`, e.g. those
+    /// returned by [`Geometry::to_geo_zm`].
+    ///
+    /// Returns an error if `zm` doesn't hold exactly one value per point.
+    fn to_gdal_zm(&self, zm: &ZmValues) -> Result<Geometry> {
+        let mut geometry = self.to_gdal()?;
+        geometry.set_zm_values(zm)?;
+        Ok(geometry)
+    }
 }
 
 impl Geometry {
     /// Create a copy of self as a `geo-types` geometry.
+    ///
+    /// Returns [`GdalError::LossyGeometryConversion`][crate::errors::GdalError::LossyGeometryConversion]
+    /// for geometries with Z or M values, which `geo-types` can't represent. Use
+    /// [`to_geo_zm`][Self::to_geo_zm] to keep them in a side-car buffer.
+    ///
+    /// Curve geometries (`CircularString`, `CompoundCurve`, `CurvePolygon`, `MultiCurve` and
+    /// `MultiSurface`) are approximated by their linear counterparts. Use
+    /// [`get_linear_geometry`][Self::get_linear_geometry] first to control the approximation.
     pub fn to_geo(&self) -> Result<geo_types::Geometry<f64>> {
         self.try_into()
     }
@@ -184,4 +206,16 @@ mod tests {
         );
         assert_eq!(geo.to_gdal().unwrap().wkt().unwrap(), wkt);
     }
+
+    #[test]
+    fn test_export_curves() {
+        let wkt =
+            "MULTISURFACE (CURVEPOLYGON (COMPOUNDCURVE (CIRCULARSTRING (0 0,1 1,2 0),(2 0,0 0))))";
+        let geo = Geometry::from_wkt(wkt).unwrap().to_geo().unwrap();
+        let geo_types::Geometry::MultiPolygon(multipolygon) = geo else {
+            panic!("Expected to get a MultiPolygon");
+        };
+        assert_eq!(multipolygon.0.len(), 1);
+        assert!(multipolygon.0[0].exterior().0.len() > 3);
+    }
 }
@@ -0,0 +1,190 @@
+use std::convert::TryInto;
+
+use gdal_sys::OGRwkbGeometryType;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::vector::{geometry_type_flatten, geometry_type_has_m, geometry_type_has_z, Geometry};
+
+/// Z and M values of a geometry, kept aside when converting to `geo-types`, whose
+/// coordinates are 2D only.
+///
+/// The values are stored in the order in which the coordinates of the `geo-types` geometry
+/// are visited: points of line strings, rings of polygons (exterior first), and the parts of
+/// collections, recursively.
+///
+/// See [`Geometry::to_geo_zm`] and [`ToGdal::to_gdal_zm`][crate::vector::ToGdal::to_gdal_zm].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ZmValues {
+    /// Z values, or `None` for a geometry without Z.
+    pub z: Option<Vec<f64>>,
+    /// M values, or `None` for a geometry without M.
+    pub m: Option<Vec<f64>>,
+}
+
+/// Calls `f` on each point and curve of `geometry`, in order.
+///
+/// The second argument of `f` tells whether the curve is a polygon ring.
+fn for_each_part(
+    geometry: &Geometry,
+    is_ring: bool,
+    f: &mut impl FnMut(&mut Geometry, bool) -> Result<()>,
+) -> Result<()> {
+    let count = geometry.geometry_count();
+    if count == 0 {
+        if geometry.point_count() == 0 {
+            return Ok(());
+        }
+        let mut part = unsafe { Geometry::with_c_geometry(geometry.c_geometry(), false) };
+        return f(&mut part, is_ring);
+    }
+    let is_polygon =
+        geometry_type_flatten(geometry.geometry_type()) == OGRwkbGeometryType::wkbPolygon;
+    for i in 0..count {
+        let part = unsafe { geometry.get_unowned_geometry(i) };
+        for_each_part(&part, is_polygon, f)?;
+    }
+    Ok(())
+}
+
+impl Geometry {
+    /// Create a copy of self as a `geo-types` geometry, along with its Z and M values.
+    ///
+    /// Unlike [`to_geo`][Self::to_geo], which fails on geometries with Z or M values,
+    /// this keeps them in a [`ZmValues`] side-car buffer, from which they can be restored
+    /// with [`ToGdal::to_gdal_zm`][crate::vector::ToGdal::to_gdal_zm].
+    ///
+    /// Unclosed polygon rings are closed by `geo-types`; the Z and M values of their first
+    /// point are repeated accordingly.
+    pub fn to_geo_zm(&self) -> Result<(geo_types::Geometry<f64>, ZmValues)> {
+        let mut linear = if self.has_curve_geometry(false) {
+            self.get_linear_geometry(0.0, &CslStringList::new())?
+        } else {
+            self.clone()
+        };
+
+        let geometry_type = linear.geometry_type();
+        let mut z = geometry_type_has_z(geometry_type).then(Vec::new);
+        let mut m = geometry_type_has_m(geometry_type).then(Vec::new);
+        for_each_part(&linear, false, &mut |part, is_ring| {
+            let n = part.point_count();
+            let (mut xs, mut ys, mut zs, mut ms) =
+                (vec![0.; n], vec![0.; n], vec![0.; n], vec![0.; n]);
+            part.read_points(&mut xs, &mut ys, Some(&mut zs), Some(&mut ms))?;
+            if is_ring && (xs[0], ys[0]) != (xs[n - 1], ys[n - 1]) {
+                zs.push(zs[0]);
+                ms.push(ms[0]);
+            }
+            if let Some(z) = &mut z {
+                z.extend(zs);
+            }
+            if let Some(m) = &mut m {
+                m.extend(ms);
+            }
+            Ok(())
+        })?;
+
+        linear.flatten_to_2d();
+        Ok((linear.try_into()?, ZmValues { z, m }))
+    }
+
+    /// Set the Z and M values of all the points of this geometry, in the order described
+    /// in [`ZmValues`].
+    pub(crate) fn set_zm_values(&mut self, zm: &ZmValues) -> Result<()> {
+        let mut point_count = 0;
+        for_each_part(self, false, &mut |part, _| {
+            point_count += part.point_count();
+            Ok(())
+        })?;
+        for values in [&zm.z, &zm.m].into_iter().flatten() {
+            if values.len() != point_count {
+                return Err(GdalError::BadArgument(format!(
+                    "expected {point_count} Z or M values, got {}",
+                    values.len()
+                )));
+            }
+        }
+
+        self.set_3d(zm.z.is_some());
+        self.set_measured(zm.m.is_some());
+        let mut offset = 0;
+        for_each_part(self, false, &mut |part, _| {
+            let n = part.point_count();
+            let (mut xs, mut ys) = (vec![0.; n], vec![0.; n]);
+            part.read_points(&mut xs, &mut ys, None, None)?;
+            let z = zm.z.as_ref().map(|z| &z[offset..offset + n]);
+            let m = zm.m.as_ref().map(|m| &m[offset..offset + n]);
+            offset += n;
+            part.set_points(&xs, &ys, z, m)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::ToGdal;
+
+    #[test]
+    fn test_to_geo_lossy() {
+        let geometry = Geometry::from_wkt("LINESTRING Z (0 0 1,1 1 2)").unwrap();
+        assert!(matches!(
+            geometry.to_geo(),
+            Err(GdalError::LossyGeometryConversion(
+                OGRwkbGeometryType::wkbLineString25D
+            ))
+        ));
+        let geometry = Geometry::from_wkt("POINT M (0 0 1)").unwrap();
+        assert!(geometry.to_geo().is_err());
+    }
+
+    #[test]
+    fn test_zm_round_trip() {
+        let wkt = "MULTIPOLYGON ZM (((0 0 1 10,0 1 2 20,1 1 3 30,0 0 1 10)),\
+                   ((2 2 4 40,2 3 5 50,3 3 6 60,2 2 4 40)))";
+        let geometry = Geometry::from_wkt(wkt).unwrap();
+        let (geo, zm) = geometry.to_geo_zm().unwrap();
+        let geo_types::Geometry::MultiPolygon(multi_polygon) = &geo else {
+            panic!("Expected to get a MultiPolygon");
+        };
+        assert_eq!(multi_polygon.0.len(), 2);
+        assert_eq!(zm.z.as_deref(), Some(&[1., 2., 3., 1., 4., 5., 6., 4.][..]));
+        assert_eq!(
+            zm.m.as_deref(),
+            Some(&[10., 20., 30., 10., 40., 50., 60., 40.][..])
+        );
+
+        let back = geo.to_gdal_zm(&zm).unwrap();
+        assert_eq!(back.geometry_type(), OGRwkbGeometryType::wkbMultiPolygonZM);
+        assert_eq!(back.wkt().unwrap(), geometry.wkt().unwrap());
+
+        let short = ZmValues {
+            z: Some(vec![0.; 3]),
+            m: None,
+        };
+        assert!(geo.to_gdal_zm(&short).is_err());
+    }
+
+    #[test]
+    fn test_zm_unclosed_ring() {
+        let geometry = Geometry::from_wkt("POLYGON Z ((0 0 1,0 1 2,1 1 3))").unwrap();
+        let (geo, zm) = geometry.to_geo_zm().unwrap();
+        let geo_types::Geometry::Polygon(polygon) = &geo else {
+            panic!("Expected to get a Polygon");
+        };
+        assert_eq!(polygon.exterior().0.len(), 4);
+        assert_eq!(zm.z, Some(vec![1., 2., 3., 1.]));
+        assert_eq!(zm.m, None);
+
+        let back = geo.to_gdal_zm(&zm).unwrap();
+        assert_eq!(back.wkt().unwrap(), "POLYGON Z ((0 0 1,0 1 2,1 1 3,0 0 1))");
+    }
+
+    #[test]
+    fn test_zm_point_2d() {
+        let geometry = Geometry::from_wkt("POINT (1 2)").unwrap();
+        let (geo, zm) = geometry.to_geo_zm().unwrap();
+        assert_eq!(zm, ZmValues::default());
+        assert_eq!(geo.to_gdal_zm(&zm).unwrap().wkt().unwrap(), "POINT (1 2)");
+    }
+}
@@ -1,6 +1,12 @@
 mod conversions;
 mod predicates;
+mod prepared;
 mod set;
 mod transformations;
+mod validity;
 
-pub use conversions::ToGdal;
+pub use conversions::{ToGdal, WkbByteOrder, WkbOptions, WkbVariant, WktOptions, ZmValues};
+pub use prepared::PreparedGeometry;
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+pub use transformations::SetPrecisionFlags;
+pub use validity::{ValidityReason, ValidityReasonKind};
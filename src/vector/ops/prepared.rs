@@ -0,0 +1,125 @@
+use std::marker::PhantomData;
+
+use gdal_sys::OGRPreparedGeometryH;
+
+use crate::errors::Result;
+use crate::utils::_last_null_pointer_err;
+use crate::vector::Geometry;
+
+/// A [`Geometry`] prepared for the fast evaluation of repeated predicates.
+///
+/// Preparing a geometry builds spatial indexes over its segments once, so that testing it
+/// against many other geometries (e.g. point-in-polygon tests against a large polygon) does
+/// not pay that cost on every call.
+///
+/// The prepared geometry borrows the [`Geometry`] it was created from.
+///
+/// # Notes
+/// This requires GDAL to be built with GEOS.
+/// Check with [`PreparedGeometry::is_supported`].
+///
+/// See: [`OGRCreatePreparedGeometry`](https://gdal.org/api/vector_c_api.html#_CPPv425OGRCreatePreparedGeometry12OGRGeometryH)
+///
+/// # Example
+/// ```rust, no_run
+/// use gdal::vector::{Geometry, PreparedGeometry};
+/// # fn main() -> gdal::errors::Result<()> {
+/// let polygon = Geometry::from_wkt("POLYGON ((0 0, 0 10, 10 10, 10 0, 0 0))")?;
+/// let prepared = PreparedGeometry::new(&polygon)?;
+/// let point = Geometry::from_wkt("POINT (5 5)")?;
+/// assert!(prepared.contains(&point));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct PreparedGeometry<'a> {
+    c_prepared: OGRPreparedGeometryH,
+    _geometry: PhantomData<&'a Geometry>,
+}
+
+impl<'a> PreparedGeometry<'a> {
+    /// Prepare `geometry` for repeated predicate evaluation.
+    ///
+    /// Returns an error if GDAL was built without GEOS.
+    pub fn new(geometry: &'a Geometry) -> Result<Self> {
+        let c_prepared = unsafe { gdal_sys::OGRCreatePreparedGeometry(geometry.c_geometry()) };
+        if c_prepared.is_null() {
+            return Err(_last_null_pointer_err("OGRCreatePreparedGeometry"));
+        }
+        Ok(Self {
+            c_prepared,
+            _geometry: PhantomData,
+        })
+    }
+
+    /// Returns `true` if prepared geometries are supported, i.e. GDAL was built with GEOS.
+    ///
+    /// See: [`OGRHasPreparedGeometrySupport`](https://gdal.org/api/vector_c_api.html#_CPPv429OGRHasPreparedGeometrySupportv)
+    pub fn is_supported() -> bool {
+        unsafe { gdal_sys::OGRHasPreparedGeometrySupport() != 0 }
+    }
+
+    /// Tests if the prepared geometry [_intersects_][Geometry::intersects] `other`.
+    ///
+    /// See: [`OGRPreparedGeometryIntersects`](https://gdal.org/api/vector_c_api.html#_CPPv429OGRPreparedGeometryIntersectsPK20OGRPreparedGeometryH12OGRGeometryH)
+    pub fn intersects(&self, other: &Geometry) -> bool {
+        let p =
+            unsafe { gdal_sys::OGRPreparedGeometryIntersects(self.c_prepared, other.c_geometry()) };
+        p != 0
+    }
+
+    /// Tests if the prepared geometry [_contains_][Geometry::contains] `other`.
+    ///
+    /// See: [`OGRPreparedGeometryContains`](https://gdal.org/api/vector_c_api.html#_CPPv427OGRPreparedGeometryContainsPK20OGRPreparedGeometryH12OGRGeometryH)
+    pub fn contains(&self, other: &Geometry) -> bool {
+        let p =
+            unsafe { gdal_sys::OGRPreparedGeometryContains(self.c_prepared, other.c_geometry()) };
+        p != 0
+    }
+}
+
+impl Drop for PreparedGeometry<'_> {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::OGRDestroyPreparedGeometry(self.c_prepared) };
+    }
+}
+
+impl Geometry {
+    /// Prepare this geometry for repeated predicate evaluation.
+    ///
+    /// See: [`PreparedGeometry`]
+    pub fn prepare(&self) -> Result<PreparedGeometry<'_>> {
+        PreparedGeometry::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prepared_predicates() {
+        assert!(PreparedGeometry::is_supported());
+
+        let polygon = Geometry::from_wkt("POLYGON ((0 0, 0 10, 10 10, 10 0, 0 0))").unwrap();
+        let prepared = polygon.prepare().unwrap();
+
+        let inside = Geometry::from_wkt("POINT (5 5)").unwrap();
+        let on_boundary = Geometry::from_wkt("POINT (0 5)").unwrap();
+        let outside = Geometry::from_wkt("POINT (15 5)").unwrap();
+
+        assert!(prepared.contains(&inside));
+        assert!(prepared.intersects(&inside));
+        assert!(!prepared.contains(&on_boundary));
+        assert!(prepared.intersects(&on_boundary));
+        assert!(!prepared.contains(&outside));
+        assert!(!prepared.intersects(&outside));
+
+        for (x, y) in [(1.0, 1.0), (9.5, 0.5), (20.0, 20.0)] {
+            let mut point = Geometry::empty(gdal_sys::OGRwkbGeometryType::wkbPoint).unwrap();
+            point.add_point_2d((x, y));
+            assert_eq!(prepared.contains(&point), polygon.contains(&point));
+            assert_eq!(prepared.intersects(&point), polygon.intersects(&point));
+        }
+    }
+}
@@ -109,6 +109,74 @@ impl Geometry {
             Some(Geometry::with_c_geometry(ogr_geom, true))
         }
     }
+
+    /// Computes the [symmetric difference][sym_difference] of `self` and `other`.
+    ///
+    /// Generates a new geometry which is the region of this geometry or the other geometry,
+    /// but not their intersection.
+    ///
+    /// # Notes
+    /// * Geometry validity is not checked, and invalid geometry will generate unpredictable results.
+    ///   Use [`Geometry::is_valid`] if validity might be in question.
+    /// * If GEOS is *not* enabled, this function will always return `None`.
+    ///   You may check for GEOS support with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// # Returns
+    /// * `Some(geometry)`: a new `Geometry` representing the computed symmetric difference
+    /// * `None`: when the symmetric difference could not be computed
+    ///
+    /// See: [`OGR_G_SymDifference`][OGR_G_SymDifference]
+    ///
+    /// [OGR_G_SymDifference]: https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_SymDifference12OGRGeometryH12OGRGeometryH
+    /// [sym_difference]: https://en.wikipedia.org/wiki/Symmetric_difference
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn sym_difference(&self, other: &Self) -> Option<Self> {
+        if !self.has_gdal_ptr() {
+            return None;
+        }
+        if !other.has_gdal_ptr() {
+            return None;
+        }
+        unsafe {
+            let ogr_geom = gdal_sys::OGR_G_SymDifference(self.c_geometry(), other.c_geometry());
+            if ogr_geom.is_null() {
+                return None;
+            }
+            Some(Geometry::with_c_geometry(ogr_geom, true))
+        }
+    }
+
+    /// Computes the union of all the components of this geometry.
+    ///
+    /// This is typically used to dissolve the polygons of a multi-polygon or geometry
+    /// collection, or to node a set of lines.
+    ///
+    /// # Notes
+    /// * Geometry validity is not checked, and invalid geometry will generate unpredictable results.
+    ///   Use [`Geometry::is_valid`] if validity might be in question.
+    /// * If GEOS is *not* enabled, this function will always return `None`.
+    ///   You may check for GEOS support with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// # Returns
+    /// * `Some(geometry)`: a new `Geometry` representing the computed union
+    /// * `None`: when the union could not be computed
+    ///
+    /// See: [`OGR_G_UnaryUnion`][OGR_G_UnaryUnion]
+    ///
+    /// [OGR_G_UnaryUnion]: https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_UnaryUnion12OGRGeometryH
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn unary_union(&self) -> Option<Self> {
+        if !self.has_gdal_ptr() {
+            return None;
+        }
+        unsafe {
+            let ogr_geom = gdal_sys::OGR_G_UnaryUnion(self.c_geometry());
+            if ogr_geom.is_null() {
+                return None;
+            }
+            Some(Geometry::with_c_geometry(ogr_geom, true))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -244,4 +312,43 @@ mod tests {
         let res = geom.difference(&other);
         assert_eq!(res.unwrap().area(), 25.0);
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_sym_difference_success() {
+        let geom =
+            Geometry::from_wkt("POLYGON ((0.0 10.0, 0.0 0.0, 10.0 0.0, 10.0 10.0, 0.0 10.0))")
+                .unwrap();
+        let other = Geometry::from_wkt("POLYGON ((1 -5, 1 1, -5 1, -5 -5, 1 -5))").unwrap();
+
+        let res = geom.sym_difference(&other).unwrap();
+        assert_eq!(res.area(), 134.0);
+    }
+
+    #[test]
+    fn test_sym_difference_no_gdal_ptr() {
+        let geom =
+            Geometry::from_wkt("POLYGON ((0.0 10.0, 0.0 0.0, 10.0 0.0, 10.0 10.0, 0.0 10.0))")
+                .unwrap();
+        let other = unsafe { Geometry::lazy_feature_geometry() };
+
+        let res = geom.sym_difference(&other);
+        assert!(res.is_none());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_unary_union() {
+        let geom = Geometry::from_wkt(
+            "MULTIPOLYGON (((0 0, 0 10, 10 10, 10 0, 0 0)), ((5 5, 5 15, 15 15, 15 5, 5 5)))",
+        )
+        .unwrap();
+
+        let res = geom.unary_union().unwrap();
+        assert_eq!(
+            res.geometry_type(),
+            ::gdal_sys::OGRwkbGeometryType::wkbPolygon
+        );
+        assert_eq!(res.area(), 175.0);
+    }
 }
@@ -1,4 +1,4 @@
-use gdal_sys::OGRErr;
+use gdal_sys::{OGRErr, OGRwkbGeometryType};
 
 use crate::cpl::CslStringList;
 use crate::errors::{GdalError, Result};
@@ -7,6 +7,20 @@ use crate::spatial_ref::SpatialRef;
 use crate::utils::_last_null_pointer_err;
 use crate::vector::Geometry;
 
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+bitflags::bitflags! {
+    /// Flags for [`Geometry::set_precision`].
+    #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+    pub struct SetPrecisionFlags: std::ffi::c_int {
+        /// Snap each vertex to the grid without preserving the topology, which is faster but
+        /// may create invalid geometries.
+        const NO_TOPO = 1;
+        /// Keep collapsed components (e.g. line strings shorter than the grid size) instead of
+        /// removing them. Only used together with [`SetPrecisionFlags::NO_TOPO`].
+        const KEEP_COLLAPSED = 2;
+    }
+}
+
 /// # Geometry Transformations
 ///
 /// These methods provide geometric transformations on a `Geometry`.
@@ -80,6 +94,186 @@ impl Geometry {
         Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
     }
 
+    /// Compute the [concave hull][ch] of this geometry.
+    ///
+    /// # Arguments
+    /// * `ratio`: the ratio of the area of the convex hull and the concave hull; `1.0` gives
+    ///   the convex hull, lower values give tighter hulls
+    /// * `allow_holes`: whether the resulting polygon may contain holes
+    ///
+    /// # Notes
+    /// This function requires GEOS library, v3.11 or above.
+    /// If OGR is built without the GEOS library, this function will always fail.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_ConcaveHull`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_ConcaveHull12OGRGeometryHdb)
+    ///
+    /// [ch]: https://en.wikipedia.org/wiki/Alpha_shape
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn concave_hull(&self, ratio: f64, allow_holes: bool) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_ConcaveHull(self.c_geometry(), ratio, allow_holes) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_ConcaveHull"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Compute the geometric centroid of this geometry, as a point.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library for geometries other than points and
+    /// (multi)polygons. Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_Centroid`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_Centroid12OGRGeometryH12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn centroid(&self) -> Result<Geometry> {
+        let centroid = Geometry::empty(OGRwkbGeometryType::wkbPoint)?;
+        let rv = unsafe { gdal_sys::OGR_G_Centroid(self.c_geometry(), centroid.c_geometry()) }
+            as OGRErr::Type;
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_G_Centroid",
+            });
+        }
+        Ok(centroid)
+    }
+
+    /// Compute a point guaranteed to lie on the surface of this geometry.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// If OGR is built without the GEOS library, this function will always fail.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_PointOnSurface`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_PointOnSurface12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn point_on_surface(&self) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_PointOnSurface(self.c_geometry()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_PointOnSurface"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Compute the boundary of this geometry.
+    ///
+    /// The boundary of a polygon is its rings as lines, and the boundary of a line is its
+    /// end points.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// If OGR is built without the GEOS library, this function will always fail.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_Boundary`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_Boundary12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn boundary(&self) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_Boundary(self.c_geometry()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_Boundary"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Build polygons from the lines of this geometry.
+    ///
+    /// The input is expected to be a `MultiLineString` (or a `GeometryCollection` of
+    /// `LineString`s) forming a fully noded network; see [`Geometry::unary_union`] for noding
+    /// a set of lines.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// If OGR is built without the GEOS library, this function will always fail.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_Polygonize`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_Polygonize12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn polygonize(&self) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_Polygonize(self.c_geometry()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_Polygonize"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Add intermediate vertices to this geometry in-place, so that no segment is longer
+    /// than `max_length`.
+    ///
+    /// See: [`OGR_G_Segmentize`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_Segmentize12OGRGeometryHd)
+    pub fn segmentize(&mut self, max_length: f64) {
+        unsafe { gdal_sys::OGR_G_Segmentize(self.c_geometry(), max_length) };
+    }
+
+    /// Compute a normalized version of this geometry, with its components and vertices in a
+    /// canonical order.
+    ///
+    /// Two normalized geometries can be compared for structural equality.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// If OGR is built without the GEOS library, this function will always fail.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_Normalize`](https://gdal.org/api/vector_c_api.html#_CPPv415OGR_G_Normalize12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn normalize(&self) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_Normalize(self.c_geometry()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_Normalize"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Compute an approximation of this geometry made only of linear geometry types.
+    ///
+    /// Circular arcs are replaced by line strings; e.g. a `CurvePolygon` becomes a `Polygon`
+    /// and a `MultiSurface` a `MultiPolygon`. Linear geometries are returned as a clone.
+    ///
+    /// # Arguments
+    /// * `max_angle_step_degrees`: the largest step in degrees along an arc, or `0.0` to use
+    ///   the default (which can be set with the `OGR_ARC_STEPSIZE` configuration option)
+    /// * `opts`: options such as `ADD_INTERMEDIATE_POINT=YES/NO`
+    ///
+    /// See: [`OGR_G_GetLinearGeometry`](https://gdal.org/api/vector_c_api.html#_CPPv423OGR_G_GetLinearGeometry12OGRGeometryHdPPc)
+    pub fn get_linear_geometry(
+        &self,
+        max_angle_step_degrees: f64,
+        opts: &CslStringList,
+    ) -> Result<Geometry> {
+        let c_geom = unsafe {
+            gdal_sys::OGR_G_GetLinearGeometry(
+                self.c_geometry(),
+                max_angle_step_degrees,
+                opts.as_ptr(),
+            )
+        };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_GetLinearGeometry"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
+    /// Compute a curve geometry from this geometry, detecting circular arcs in its line strings.
+    ///
+    /// This is the reverse of [`get_linear_geometry`][Self::get_linear_geometry]: line strings
+    /// that approximate arcs are converted back to `CircularString`s or `CompoundCurve`s. When
+    /// no arc is found, the geometry is returned as a clone.
+    ///
+    /// See: [`OGR_G_GetCurveGeometry`](https://gdal.org/api/vector_c_api.html#_CPPv422OGR_G_GetCurveGeometry12OGRGeometryHPPc)
+    pub fn get_curve_geometry(&self, opts: &CslStringList) -> Result<Geometry> {
+        let c_geom = unsafe { gdal_sys::OGR_G_GetCurveGeometry(self.c_geometry(), opts.as_ptr()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_GetCurveGeometry"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
     /// Return a [Delaunay triangulation of][dt] the vertices of the geometry.
     ///
     /// # Arguments
@@ -155,6 +349,25 @@ impl Geometry {
         Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
     }
 
+    /// Return a copy of this geometry with its coordinates rounded to a grid of the given size.
+    ///
+    /// By default, the topology is preserved: the output is valid, and components that
+    /// collapse are removed. Use `flags` to change that behavior.
+    ///
+    /// # Notes
+    /// This function requires GDAL 3.9 or later, and the GEOS library.
+    ///
+    /// See: [`OGR_G_SetPrecision`](https://gdal.org/api/vector_c_api.html#_CPPv418OGR_G_SetPrecision12OGRGeometryHdi)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    pub fn set_precision(&self, grid_size: f64, flags: SetPrecisionFlags) -> Result<Geometry> {
+        let c_geom =
+            unsafe { gdal_sys::OGR_G_SetPrecision(self.c_geometry(), grid_size, flags.bits()) };
+        if c_geom.is_null() {
+            return Err(_last_null_pointer_err("OGR_G_SetPrecision"));
+        };
+        Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
+    }
+
     /// Attempts to make an invalid geometry valid without losing vertices.
     ///
     /// Already-valid geometries are cloned without further intervention.
@@ -216,6 +429,111 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_concave_hull() -> Result<()> {
+        let points = Geometry::from_wkt("MULTIPOINT ((0 0),(10 0),(10 10),(0 10),(5 1))")?;
+        let convex = points.concave_hull(1.0, false)?;
+        assert_eq!(convex.normalize()?, points.convex_hull()?.normalize()?);
+        let concave = points.concave_hull(0.0, false)?;
+        assert!(concave.area() < convex.area());
+        Ok(())
+    }
+
+    #[test]
+    fn test_centroid() -> Result<()> {
+        let square = Geometry::from_wkt("POLYGON ((0 0,0 2,2 2,2 0,0 0))")?;
+        assert_eq!(square.centroid()?, Geometry::from_wkt("POINT (1 1)")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_point_on_surface() -> Result<()> {
+        let u_shape = Geometry::from_wkt("POLYGON ((0 0,0 3,1 3,1 1,2 1,2 3,3 3,3 0,0 0))")?;
+        let point = u_shape.point_on_surface()?;
+        assert!(u_shape.contains(&point));
+        // the centroid of this shape lies outside of it
+        assert!(!u_shape.contains(&u_shape.centroid()?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_boundary() -> Result<()> {
+        let square = Geometry::from_wkt("POLYGON ((0 0,0 2,2 2,2 0,0 0))")?;
+        let expected = Geometry::from_wkt("LINESTRING (0 0,0 2,2 2,2 0,0 0)")?;
+        assert_eq!(square.boundary()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_polygonize() -> Result<()> {
+        let lines =
+            Geometry::from_wkt("MULTILINESTRING ((0 0,0 2),(0 2,2 2),(2 2,2 0),(2 0,0 0))")?;
+        let polygons = lines.polygonize()?;
+        assert_eq!(polygons.geometry_count(), 1);
+        assert_eq!(polygons.area(), 4.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_segmentize() -> Result<()> {
+        let mut line = Geometry::from_wkt("LINESTRING (0 0,10 0)")?;
+        line.segmentize(2.5);
+        assert_eq!(line.point_count(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize() -> Result<()> {
+        let a = Geometry::from_wkt("POLYGON ((0 0,0 2,2 2,2 0,0 0))")?;
+        let b = Geometry::from_wkt("POLYGON ((2 2,2 0,0 0,0 2,2 2))")?;
+        assert_ne!(a.wkt()?, b.wkt()?);
+        assert_eq!(a.normalize()?.wkt()?, b.normalize()?.wkt()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_linear_and_curve_geometry() -> Result<()> {
+        let curve = Geometry::from_wkt(
+            "CURVEPOLYGON (COMPOUNDCURVE (CIRCULARSTRING (0 0,1 1,2 0),(2 0,0 0)))",
+        )?;
+        let linear = curve.get_linear_geometry(1.0, &CslStringList::new())?;
+        assert_eq!(
+            linear.geometry_type(),
+            gdal_sys::OGRwkbGeometryType::wkbPolygon
+        );
+        assert!(!linear.has_curve_geometry(false));
+        // one point per degree along the half circle
+        assert!(linear.get_geometry(0).point_count() > 180);
+        assert!((linear.area() - std::f64::consts::FRAC_PI_2).abs() < 1e-3);
+
+        let coarse = curve.get_linear_geometry(45.0, &CslStringList::new())?;
+        assert!(coarse.get_geometry(0).point_count() < 10);
+
+        let back = linear.get_curve_geometry(&CslStringList::new())?;
+        assert!(back.has_curve_geometry(true));
+        Ok(())
+    }
+
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    #[test]
+    fn test_set_precision() -> Result<()> {
+        let line = Geometry::from_wkt("LINESTRING (0.123 0.456,1.789 2.011,1.8 2.02)")?;
+        let snapped = line.set_precision(0.1, SetPrecisionFlags::empty())?;
+        // the last two points collapse
+        assert_eq!(snapped.wkt()?, "LINESTRING (0.1 0.5,1.8 2.0)");
+
+        let tiny = Geometry::from_wkt("LINESTRING (0.01 0.01,0.02 0.02)")?;
+        assert!(tiny
+            .set_precision(1.0, SetPrecisionFlags::empty())?
+            .is_empty());
+        let kept = tiny.set_precision(
+            1.0,
+            SetPrecisionFlags::NO_TOPO | SetPrecisionFlags::KEEP_COLLAPSED,
+        )?;
+        assert!(!kept.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_delaunay_triangulation() -> Result<()> {
         let square = Geometry::from_wkt("POLYGON ((0 1,1 1,1 0,0 0,0 1))")?;
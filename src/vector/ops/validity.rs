@@ -0,0 +1,197 @@
+use std::ffi::{c_char, c_void};
+
+use gdal_sys::{CPLErr, CPLErrorNum};
+
+use crate::utils::_string;
+use crate::vector::Geometry;
+
+/// The kind of problem making a geometry invalid, as reported by GEOS.
+///
+/// See [`Geometry::validity_reason`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ValidityReasonKind {
+    /// A polygon hole lies outside its shell.
+    HoleOutsideShell,
+    /// A polygon hole lies inside another hole.
+    NestedHoles,
+    /// The interior of a polygon is split in several parts by its holes.
+    DisconnectedInterior,
+    /// Two parts of the geometry intersect, e.g. the rings of a polygon cross each other.
+    SelfIntersection,
+    /// A ring intersects itself.
+    RingSelfIntersection,
+    /// A polygon of a multi-polygon lies inside another one.
+    NestedShells,
+    /// The geometry has two identical rings.
+    DuplicateRings,
+    /// A component has too few points, e.g. a line string with a single point or a ring
+    /// with fewer than 4 points.
+    TooFewPoints,
+    /// A coordinate is not finite.
+    InvalidCoordinate,
+    /// A ring is not closed.
+    RingNotClosed,
+    /// Any other problem, see [`ValidityReason::message`].
+    Other,
+}
+
+impl ValidityReasonKind {
+    fn from_message(message: &str) -> Self {
+        let message = message.to_lowercase();
+        if message.starts_with("hole lies outside shell") {
+            Self::HoleOutsideShell
+        } else if message.starts_with("holes are nested") || message.starts_with("nested holes") {
+            Self::NestedHoles
+        } else if message.starts_with("interior is disconnected") {
+            Self::DisconnectedInterior
+        } else if message.starts_with("self-intersection") {
+            Self::SelfIntersection
+        } else if message.starts_with("ring self-intersection") {
+            Self::RingSelfIntersection
+        } else if message.starts_with("nested shells") {
+            Self::NestedShells
+        } else if message.starts_with("duplicate rings") {
+            Self::DuplicateRings
+        } else if message.starts_with("too few") || message.contains("invalid number of points") {
+            Self::TooFewPoints
+        } else if message.starts_with("invalid coordinate") {
+            Self::InvalidCoordinate
+        } else if message.contains("not closed") || message.contains("closed linestring") {
+            Self::RingNotClosed
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// Why a geometry is invalid, see [`Geometry::validity_reason`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidityReason {
+    /// The kind of problem.
+    pub kind: ValidityReasonKind,
+    /// The message reported by GEOS, e.g. `"Self-intersection at or near point 1 1"`.
+    pub message: String,
+    /// A point at or near the problem, if known.
+    pub location: Option<Geometry>,
+}
+
+impl ValidityReason {
+    fn from_message(message: String) -> Self {
+        let location = message
+            .split_once(" at or near point ")
+            .and_then(|(_, coords)| {
+                let coords = coords
+                    .split_whitespace()
+                    .map(str::parse::<f64>)
+                    .collect::<Result<Vec<_>, _>>()
+                    .ok()?;
+                let mut point = Geometry::empty(gdal_sys::OGRwkbGeometryType::wkbPoint).ok()?;
+                match coords[..] {
+                    [x, y] => point.add_point_2d((x, y)),
+                    [x, y, z] => point.add_point((x, y, z)),
+                    _ => return None,
+                }
+                Some(point)
+            });
+        Self {
+            kind: ValidityReasonKind::from_message(&message),
+            message,
+            location,
+        }
+    }
+}
+
+impl Geometry {
+    /// Explain why this geometry is invalid, or return `None` if it is
+    /// [valid][Self::is_valid].
+    ///
+    /// The first problem reported by GEOS is returned, with its location when GEOS reports
+    /// one. Other problems may remain after fixing it.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library. Without it, geometries are always reported
+    /// as invalid, with [`ValidityReasonKind::Other`].
+    ///
+    /// See: [`OGR_G_IsValid`](https://gdal.org/api/vector_c_api.html#_CPPv413OGR_G_IsValid12OGRGeometryH)
+    pub fn validity_reason(&self) -> Option<ValidityReason> {
+        unsafe extern "C" fn collect_messages(
+            error_type: CPLErr::Type,
+            _error_num: CPLErrorNum,
+            error_msg_ptr: *const c_char,
+        ) {
+            if error_type == CPLErr::CE_Warning || error_type == CPLErr::CE_Failure {
+                let messages = gdal_sys::CPLGetErrorHandlerUserData() as *mut Vec<String>;
+                (*messages).push(_string(error_msg_ptr).unwrap_or_default());
+            }
+        }
+
+        // GEOS reports the reason of the invalidity as a warning, which we capture with a
+        // thread-local error handler instead of printing it
+        let mut messages: Vec<String> = Vec::new();
+        let is_valid = unsafe {
+            gdal_sys::CPLPushErrorHandlerEx(
+                Some(collect_messages),
+                &mut messages as *mut Vec<String> as *mut c_void,
+            );
+            let rv = gdal_sys::OGR_G_IsValid(self.c_geometry());
+            gdal_sys::CPLPopErrorHandler();
+            gdal_sys::CPLErrorReset();
+            rv != 0
+        };
+        if is_valid {
+            return None;
+        }
+
+        let message = messages
+            .into_iter()
+            .find(|m| !m.is_empty())
+            .unwrap_or_else(|| "Invalid geometry".to_string());
+        // strip the GEOS exception name, if any
+        let message = match message.split_once("Exception: ") {
+            Some((_, message)) => message.to_string(),
+            None => message,
+        };
+        Some(ValidityReason::from_message(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validity_reason() {
+        let valid = Geometry::from_wkt("POLYGON ((0 0,0 1,1 1,1 0,0 0))").unwrap();
+        assert_eq!(valid.validity_reason(), None);
+
+        let bowtie = Geometry::from_wkt("POLYGON ((0 0,2 2,2 0,0 2,0 0))").unwrap();
+        let reason = bowtie.validity_reason().unwrap();
+        assert!(!bowtie.is_valid());
+        assert_eq!(reason.kind, ValidityReasonKind::SelfIntersection);
+        assert_eq!(
+            reason.location,
+            Some(Geometry::from_wkt("POINT (1 1)").unwrap())
+        );
+
+        let outside =
+            Geometry::from_wkt("POLYGON ((0 0,0 1,1 1,1 0,0 0),(5 5,5 6,6 6,6 5,5 5))").unwrap();
+        let reason = outside.validity_reason().unwrap();
+        assert_eq!(reason.kind, ValidityReasonKind::HoleOutsideShell);
+        assert!(reason.location.is_some());
+    }
+
+    #[test]
+    fn test_validity_reason_from_message() {
+        let reason = ValidityReason::from_message(
+            "Too few distinct points in geometry component at or near point 1.5 2 3".to_string(),
+        );
+        assert_eq!(reason.kind, ValidityReasonKind::TooFewPoints);
+        assert_eq!(reason.location.unwrap().get_point(0), (1.5, 2.0, 3.0));
+
+        let reason = ValidityReason::from_message(
+            "Points of LinearRing do not form a closed linestring".to_string(),
+        );
+        assert_eq!(reason.kind, ValidityReasonKind::RingNotClosed);
+        assert_eq!(reason.location, None);
+    }
+}
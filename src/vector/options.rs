@@ -1,4 +1,5 @@
 use crate::spatial_ref::SpatialRef;
+use crate::vector::CoordinatePrecision;
 use gdal_sys::OGRwkbGeometryType;
 
 /// Parameters for [`crate::Dataset::create_layer`].
@@ -12,6 +13,22 @@ pub struct LayerOptions<'a> {
     pub ty: OGRwkbGeometryType::Type,
     /// Additional driver-specific options to pass to GDAL, in the form `name=value`.
     pub options: Option<&'a [&'a str]>,
+    /// Create the layer with the multi-part variant of `ty` (e.g. `MultiPolygon` for
+    /// `Polygon`), like `ogr2ogr -nlt PROMOTE_TO_MULTI`.
+    ///
+    /// Single-part geometries written with [`LayerAccess::create_feature_promoted`] are then
+    /// promoted to the layer type, so that layers mixing single- and multi-part inputs can be
+    /// written.
+    ///
+    /// [`LayerAccess::create_feature_promoted`]: crate::vector::LayerAccess::create_feature_promoted
+    pub promote_to_multi: bool,
+    /// The resolution of the coordinates of the geometry field, or `None` if unknown.
+    ///
+    /// Drivers that support it (e.g. GeoPackage and FlatGeobuf) store it in the dataset
+    /// and round the coordinates of the written geometries accordingly.
+    ///
+    /// Requires GDAL 3.9 or later; setting it with older versions is an error.
+    pub coordinate_precision: Option<CoordinatePrecision>,
 }
 
 const EMPTY_LAYER_NAME: &str = "";
@@ -24,6 +41,8 @@ impl Default for LayerOptions<'_> {
             srs: None,
             ty: OGRwkbGeometryType::wkbUnknown,
             options: None,
+            promote_to_multi: false,
+            coordinate_precision: None,
         }
     }
 }